@@ -0,0 +1,87 @@
+use std::str::FromStr;
+
+use crate::error::ValueError;
+use crate::expression::CharacterSet;
+use crate::parse::{character_set, Flags};
+use crate::tokens::Tokenise;
+use crate::tree::{tree, Node};
+
+/// The characters to choose from when generating text for wildcards and negated classes
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Alphabet(CharacterSet);
+
+impl Alphabet {
+    /// Printable ASCII, from space to tilde
+    pub fn printable_ascii() -> Self {
+        Alphabet(CharacterSet::new([(' ', '~')]))
+    }
+
+    /// Every Unicode scalar value: large sets of characters will be sampled when enumerated
+    pub fn unicode() -> Self {
+        Alphabet(CharacterSet::new([('\0', char::MAX)]))
+    }
+
+    pub(crate) fn characters(&self) -> &CharacterSet {
+        &self.0
+    }
+}
+
+impl Default for Alphabet {
+    fn default() -> Self {
+        Alphabet::printable_ascii()
+    }
+}
+
+/// Accepts `ascii`, `unicode`, or a character class such as `[a-z0-9]`
+impl FromStr for Alphabet {
+    type Err = ValueError;
+
+    fn from_str(s: &str) -> Result<Self, ValueError> {
+        match s {
+            "ascii" => Ok(Alphabet::printable_ascii()),
+            "unicode" => Ok(Alphabet::unicode()),
+            _ => match tree(s.chars().tokenise())? {
+                Node::Class(class) => Ok(Alphabet(character_set(
+                    &class,
+                    &Alphabet::unicode(),
+                    Flags::default(),
+                ))),
+                _ => Err(ValueError::Unknown {
+                    expected: "`ascii`, `unicode`, or a character class",
+                    found: s.to_string(),
+                }),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod test {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::alphabet::Alphabet;
+    use crate::error::ValueError;
+    use crate::expression::CharacterSet;
+
+    #[rstest]
+    #[case("ascii", CharacterSet::new([(' ', '~')]))]
+    #[case("[a-c]", CharacterSet::new([('a', 'c')]))]
+    #[case("[^\0-\u{10FFFE}]", CharacterSet::new([('\u{10FFFF}', '\u{10FFFF}')]))]
+    fn test_alphabet(#[case] input: &str, #[case] expected: CharacterSet) {
+        let alphabet: Alphabet = input.parse().expect("Failed to parse alphabet");
+        assert_eq!(&expected, alphabet.characters());
+    }
+
+    #[rstest]
+    #[case("abc", true)]
+    #[case("[a-c]d", true)]
+    #[case("[a-c", false)]
+    fn test_invalid(#[case] input: &str, #[case] unknown: bool) {
+        let error = input
+            .parse::<Alphabet>()
+            .expect_err("Parsed invalid alphabet");
+        assert_eq!(unknown, matches!(error, ValueError::Unknown { .. }));
+    }
+}
@@ -0,0 +1,368 @@
+use std::mem::take;
+
+use crate::alphabet::Alphabet;
+use crate::error::Result;
+use crate::expression::{Arena, Direction, Id};
+use crate::parse::{parse, parse_node, CaptureGroup, Flags};
+use crate::tokens::TokenMeta;
+use crate::tree::group::Group;
+use crate::tree::Node;
+
+/// Notes on how parts of an expression affect what it matches
+pub(crate) fn analyse(tree: &Node, alphabet: &Alphabet, flags: Flags) -> Result<Vec<String>> {
+    let mut findings = regularity(tree);
+    findings.extend(satisfiability(tree, alphabet, flags)?);
+    findings.extend(greediness(tree, alphabet, flags)?);
+    Ok(findings)
+}
+
+/// Whether the expression describes a regular language, which is what anything that treats it
+/// as an automaton, like lowering lookarounds, relies on
+pub(crate) fn is_regular(tree: &Node) -> bool {
+    !descendants(tree)
+        .iter()
+        .any(|node| matches!(node, Node::Backreference(..)))
+}
+
+/// Reports each backreference, since any one of them stops the language being regular
+fn regularity(tree: &Node) -> Vec<String> {
+    descendants(tree)
+        .into_iter()
+        .filter_map(|node| match node {
+            Node::Backreference(reference, meta) => Some(format!(
+                "Backreference at position {} makes the expression non-regular: it repeats \
+                 whatever group {reference} captured, which no finite automaton can do",
+                meta.position
+            )),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Reports the assertions that stop the branch of the expression they're in matching anything,
+/// when it would match something without them
+fn satisfiability(tree: &Node, alphabet: &Alphabet, flags: Flags) -> Result<Vec<String>> {
+    // The variants with and without each assertion share most of their parts
+    let mut arena = Arena::default();
+    let mut findings = vec![];
+    for node in descendants(tree) {
+        let Node::Assertion(assertion, meta) = node else {
+            continue;
+        };
+        let branch = narrow(tree, meta);
+        let with = parse(&branch, alphabet, flags, &mut arena)?;
+        if arena.example(with).is_some() {
+            continue;
+        }
+        let without = rewrite(&branch, &|node| match node {
+            Node::Assertion(_, m) if m == meta => Some(Node::Empty),
+            _ => None,
+        });
+        let without = parse(&without, alphabet, flags, &mut arena)?;
+        if let Some(example) = arena.example(without) {
+            findings.push(format!(
+                "Assertion {assertion} at position {} can't be satisfied: nothing matches with it \
+                 in place, but {example:?} would without it",
+                meta.position
+            ));
+        }
+    }
+    Ok(findings)
+}
+
+/// Reports, for each lazy or possessive quantifier and atomic group, whether making it greedy
+/// would change which strings match, or only how quickly they are matched
+fn greediness(tree: &Node, alphabet: &Alphabet, flags: Flags) -> Result<Vec<String>> {
+    let mut arena = Arena::default();
+    let expression = parse(tree, alphabet, flags, &mut arena)?;
+    let mut findings = vec![];
+    for node in descendants(tree) {
+        // What it's called, and why a string might only match one way or the other
+        let (description, position, if_greedy, if_not) = match node {
+            Node::Lazy(_, _, meta) => (
+                "Lazy quantifier",
+                meta.position,
+                "only matches if it's greedy",
+                "only matches because it's lazy",
+            ),
+            Node::Possessive(_, _, meta) => (
+                "Possessive quantifier",
+                meta.position,
+                "only matches if it can backtrack",
+                "only matches because it can't backtrack",
+            ),
+            Node::Group(Group::Atomic(meta), _) => (
+                "Atomic group",
+                meta.position,
+                "only matches if it can backtrack",
+                "only matches because it can't backtrack",
+            ),
+            _ => continue,
+        };
+        let greedy = parse(&relax(tree, position), alphabet, flags, &mut arena)?;
+        let witness =
+            |from: Id, other: Id| arena.enumerate(from).find(|s| !arena.is_match(other, s));
+        let witness = witness(greedy, expression)
+            .map(|w| (w, if_greedy))
+            .or_else(|| witness(expression, greedy).map(|w| (w, if_not)));
+        findings.push(match witness {
+            Some((witness, reason)) => format!(
+                "{description} at position {position} changes which strings match: {witness:?} \
+                 {reason}"
+            ),
+            None => format!(
+                "{description} at position {position} only affects performance: no generated \
+                 example matches differently"
+            ),
+        });
+    }
+    Ok(findings)
+}
+
+/// A part of a sequence, with its lookarounds picked out
+enum Part {
+    Text(Id),
+    Lookaround(Direction, bool, Id),
+}
+
+impl Part {
+    fn expression(self, arena: &mut Arena) -> Id {
+        match self {
+            Part::Text(e) => e,
+            Part::Lookaround(direction, negated, expression) => {
+                arena.lookaround(direction, negated, expression)
+            }
+        }
+    }
+}
+
+/// Converts the top-level sequence of an expression, lowering its lookarounds into intersections
+/// and differences, so that candidates are generated to fit them.
+///
+/// At the top level, the text after a lookahead runs to the end of the whole text and the text
+/// before a lookbehind runs from its start, so the lowered form matches exactly the same strings.
+/// That includes lookarounds in non-capturing groups in the top-level sequence, whose contents
+/// are part of it. Lookarounds anywhere else are left as assertions.
+pub(crate) fn lower_lookarounds(
+    sequence: &[Node],
+    alphabet: &Alphabet,
+    flags: Flags,
+    captures: &mut Vec<CaptureGroup>,
+    arena: &mut Arena,
+) -> Result<Id> {
+    let mut parts = vec![];
+    split(sequence, alphabet, flags, captures, arena, &mut parts)?;
+
+    // A lookbehind constrains everything before it...
+    let mut before: Vec<Part> = vec![];
+    for part in parts {
+        match part {
+            Part::Lookaround(Direction::Behind, negated, other) => {
+                let text = take(&mut before)
+                    .into_iter()
+                    .map(|part| part.expression(arena))
+                    .collect();
+                let text = arena.sequence(text);
+                before.push(Part::Text(lower(
+                    arena,
+                    text,
+                    other,
+                    Direction::Behind,
+                    negated,
+                )));
+            }
+            part => before.push(part),
+        }
+    }
+    // ...and a lookahead everything after it
+    let mut after = vec![];
+    for part in before.into_iter().rev() {
+        match part {
+            Part::Lookaround(direction, negated, other) => {
+                let text = arena.sequence(take(&mut after));
+                after.push(lower(arena, text, other, direction, negated));
+            }
+            Part::Text(e) => after.insert(0, e),
+        }
+    }
+    Ok(arena.sequence(after))
+}
+
+/// Converts the nodes of a sequence into parts, with the contents of any non-capturing groups
+/// among them converted in their place
+fn split(
+    sequence: &[Node],
+    alphabet: &Alphabet,
+    mut flags: Flags,
+    captures: &mut Vec<CaptureGroup>,
+    arena: &mut Arena,
+    parts: &mut Vec<Part>,
+) -> Result<()> {
+    for node in sequence {
+        let part = match node {
+            Node::Group(Group::Flags(modifiers), _) => {
+                flags = flags.with(modifiers);
+                continue;
+            }
+            Node::Group(group @ (Group::NonCapturing | Group::Scoped(_)), content) => {
+                let flags = match group {
+                    Group::Scoped(modifiers) => flags.with(modifiers),
+                    _ => flags,
+                };
+                let content = match content.as_ref() {
+                    Node::Sequence(nodes) => nodes.as_slice(),
+                    node => std::slice::from_ref(node),
+                };
+                split(content, alphabet, flags, captures, arena, parts)?;
+                continue;
+            }
+            Node::Group(Group::Lookahead { negated }, content) => Part::Lookaround(
+                Direction::Ahead,
+                *negated,
+                parse_node(content, alphabet, flags, captures, arena)?,
+            ),
+            Node::Group(Group::Lookbehind { negated }, content) => Part::Lookaround(
+                Direction::Behind,
+                *negated,
+                parse_node(content, alphabet, flags, captures, arena)?,
+            ),
+            _ => Part::Text(parse_node(node, alphabet, flags, captures, arena)?),
+        };
+        parts.push(part);
+    }
+    Ok(())
+}
+
+fn lower(arena: &mut Arena, text: Id, other: Id, direction: Direction, negated: bool) -> Id {
+    if negated {
+        arena.difference(text, other, direction)
+    } else {
+        arena.intersection(text, other, direction)
+    }
+}
+
+/// The node and everything inside it, outermost first
+fn descendants(node: &Node) -> Vec<&Node> {
+    let mut nodes = vec![node];
+    for child in node.children() {
+        nodes.extend(descendants(child));
+    }
+    nodes
+}
+
+/// The tree with each alternation that the assertion at `meta` is in cut down to the alternative
+/// it's in, so that the other alternatives can't hide that it stops its own matching anything
+fn narrow(node: &Node, meta: &TokenMeta) -> Node {
+    rewrite(node, &|node| match node {
+        Node::Alternation(alternatives) => alternatives
+            .iter()
+            .find(|alternative| {
+                descendants(alternative)
+                    .iter()
+                    .any(|n| matches!(n, Node::Assertion(_, m) if m == meta))
+            })
+            .map(|alternative| narrow(alternative, meta)),
+        _ => None,
+    })
+}
+
+/// The tree with the lazy or possessive quantifier or atomic group at `position` made greedy
+fn relax(node: &Node, position: usize) -> Node {
+    rewrite(node, &|node| match node {
+        Node::Lazy(content, quantifier, meta) if meta.position == position => {
+            Some(Node::Augmented(content.clone(), quantifier.clone()))
+        }
+        Node::Possessive(content, quantifier, meta) if meta.position == position => {
+            Some(Node::Augmented(content.clone(), quantifier.clone()))
+        }
+        Node::Group(Group::Atomic(meta), content) if meta.position == position => {
+            Some(Node::Group(Group::NonCapturing, content.clone()))
+        }
+        _ => None,
+    })
+}
+
+/// The tree with each node that `change` gives a replacement for replaced, looking no further
+/// into the nodes it replaces
+fn rewrite(node: &Node, change: &dyn Fn(&Node) -> Option<Node>) -> Node {
+    if let Some(replacement) = change(node) {
+        return replacement;
+    }
+    let rewrite = |node: &Node| Box::new(rewrite(node, change));
+    match node {
+        Node::Sequence(sequence) => Node::Sequence(sequence.iter().map(|n| *rewrite(n)).collect()),
+        Node::Capture(content) => Node::Capture(rewrite(content)),
+        Node::Group(group, content) => Node::Group(group.clone(), rewrite(content)),
+        Node::Augmented(content, quantifier) => {
+            Node::Augmented(rewrite(content), quantifier.clone())
+        }
+        Node::Lazy(content, quantifier, meta) => {
+            Node::Lazy(rewrite(content), quantifier.clone(), *meta)
+        }
+        Node::Possessive(content, quantifier, meta) => {
+            Node::Possessive(rewrite(content), quantifier.clone(), *meta)
+        }
+        Node::Alternation(alternatives) => {
+            Node::Alternation(alternatives.iter().map(|n| *rewrite(n)).collect())
+        }
+        Node::Empty
+        | Node::Tokens(_)
+        | Node::Special(_)
+        | Node::Assertion(..)
+        | Node::Class(_)
+        | Node::Repetition(_)
+        | Node::Backreference(..) => node.clone(),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod test {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::alphabet::Alphabet;
+    use crate::analysis::analyse;
+    use crate::parse::Flags;
+    use crate::tokens::Tokenise;
+    use crate::tree::tree;
+
+    #[rstest]
+    #[case("a*b", vec![])]
+    #[case("a*?b", vec!["Lazy quantifier at position 2 only affects performance: no generated example matches differently"])]
+    #[case("(?>a+?)a", vec![
+        "Atomic group at position 0 changes which strings match: \"aaa\" only matches if it can backtrack",
+        "Lazy quantifier at position 5 changes which strings match: \"aa\" only matches because it's lazy",
+    ])]
+    #[case("a*+a", vec!["Possessive quantifier at position 2 changes which strings match: \"a\" only matches if it can backtrack"])]
+    #[case("a++b", vec!["Possessive quantifier at position 2 only affects performance: no generated example matches differently"])]
+    #[case("(?>a|ab)c", vec!["Atomic group at position 0 changes which strings match: \"abc\" only matches if it can backtrack"])]
+    #[case("x(?>a{2}?)", vec![
+        "Atomic group at position 1 only affects performance: no generated example matches differently",
+        "Lazy quantifier at position 8 only affects performance: no generated example matches differently",
+    ])]
+    #[case("(a)\\1(?<x>b)(?P=x)", vec![
+        "Backreference at position 3 makes the expression non-regular: it repeats whatever group 1 captured, which no finite automaton can do",
+        "Backreference at position 12 makes the expression non-regular: it repeats whatever group \"x\" captured, which no finite automaton can do",
+    ])]
+    #[case("(a)\\1++", vec![
+        "Backreference at position 3 makes the expression non-regular: it repeats whatever group 1 captured, which no finite automaton can do",
+        "Possessive quantifier at position 6 only affects performance: no generated example matches differently",
+    ])]
+    #[case("a^b", vec!["Assertion ^ at position 1 can't be satisfied: nothing matches with it in place, but \"ab\" would without it"])]
+    #[case("a\\bb|c\\Z\\n?d", vec![
+        "Assertion \\b at position 1 can't be satisfied: nothing matches with it in place, but \"ab\" would without it",
+        "Assertion \\Z at position 6 can't be satisfied: nothing matches with it in place, but \"c\\nd\" would without it",
+    ])]
+    #[case("\\bfoo\\b", vec![])]
+    #[case("a^b|c", vec!["Assertion ^ at position 1 can't be satisfied: nothing matches with it in place, but \"ab\" would without it"])]
+    #[case("x(?:y|a$b)", vec!["Assertion $ at position 7 can't be satisfied: nothing matches with it in place, but \"xab\" would without it"])]
+    #[case("a(?:\\Bb|c)", vec![])]
+    #[case("(?:a|^)b", vec![])]
+    fn test_analyse(#[case] input: &str, #[case] expected: Vec<&str>) {
+        let tree = tree(input.to_string().tokenise()).expect("Failed to tree");
+        let actual =
+            analyse(&tree, &Alphabet::default(), Flags::default()).expect("Failed to analyse");
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,247 @@
+//! The structure of a parsed pattern, which stays the same whatever syntax the pattern was
+//! written in.
+//!
+//! Use [`Visitor`] to inspect a pattern and [`Fold`] to rewrite one, then
+//! [`Pattern::from_ast`](crate::Pattern::from_ast) to turn the result back into a pattern, or
+//! [`Node::to_pattern`] to write it out as an expression.
+
+use crate::error::{ParseError, Result};
+use crate::parse::bounds;
+use crate::tokens::Token;
+use crate::tree;
+use crate::tree::class::CharacterClass;
+use crate::tree::special::Special;
+
+pub use crate::ast::fold::Fold;
+pub use crate::ast::visit::Visitor;
+pub use crate::tree::assertion::Assertion;
+pub use crate::tree::class::{NamedClass, Shorthand};
+pub use crate::tree::group::{Flag, Modifiers};
+pub use crate::tree::reference::Reference;
+
+mod fold;
+mod print;
+mod visit;
+
+/// A part of a pattern
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Node {
+    /// Matches the empty string
+    Empty,
+    /// Matches the text exactly
+    Literal(String),
+    /// `.`, which matches any character, apart from a newline unless `s` is set
+    AnyCharacter,
+    /// Matches any one character in the class
+    Class(Class),
+    /// Matches each node in turn
+    Sequence(Vec<Node>),
+    /// Matches any one of the nodes
+    Alternation(Vec<Node>),
+    /// Matches the node a number of times
+    Repeat(Repeat),
+    /// A parenthesised group
+    Group(Group),
+    /// Matches nothing, but only where the assertion holds
+    Assertion(Assertion),
+    /// Matches the text a capture group captured again
+    Backreference(Reference),
+    /// `(?i)`, which changes flags for the rest of the enclosing group
+    Flags(Modifiers),
+}
+
+/// A square-bracket class, or a shorthand like `\d` on its own
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Class {
+    /// Whether the class matches the characters that aren't in its items
+    pub negated: bool,
+    pub items: Vec<ClassItem>,
+}
+
+impl Class {
+    /// A class matching the characters in the items, or those not in them if it's negated
+    pub fn new(negated: bool, items: Vec<ClassItem>) -> Self {
+        Self { negated, items }
+    }
+}
+
+/// Some of the characters in a class
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ClassItem {
+    /// The characters from the first to the second, inclusive
+    Range(char, char),
+    /// `\d`, `\w`, `\s` or their negations
+    Shorthand(Shorthand),
+    /// `\p{name}`, or `\P{name}` when it's negated
+    Property { negated: bool, name: String },
+    /// `[:name:]`
+    Named(NamedClass),
+    /// `[=c=]`, the character and its accented forms
+    Equivalence(char),
+}
+
+/// A node repeated between `min` and `max` times
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Repeat {
+    pub node: Box<Node>,
+    pub min: u32,
+    /// The most repetitions, or `None` if there's no limit
+    pub max: Option<u32>,
+    pub greed: Greed,
+}
+
+impl Repeat {
+    /// The node repeated between `min` and `max` times
+    pub fn new(node: Node, min: u32, max: Option<u32>, greed: Greed) -> Self {
+        Self {
+            node: Box::new(node),
+            min,
+            max,
+            greed,
+        }
+    }
+}
+
+/// How a repetition chooses how many times to match
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum Greed {
+    /// As many as possible, giving some back if what follows doesn't match
+    Greedy,
+    /// As few as possible, like `*?`
+    Lazy,
+    /// As many as possible, never giving any back, like `*+`
+    Possessive,
+}
+
+/// A node in parentheses
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Group {
+    pub kind: GroupKind,
+    pub node: Box<Node>,
+}
+
+impl Group {
+    /// The node in a group of the given kind
+    pub fn new(kind: GroupKind, node: Node) -> Self {
+        Self {
+            kind,
+            node: Box::new(node),
+        }
+    }
+}
+
+/// What a group does with its contents
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum GroupKind {
+    /// `(...)` or `(?<name>...)`
+    Capturing { name: Option<String> },
+    /// `(?:...)`
+    NonCapturing,
+    /// `(?>...)`
+    Atomic,
+    /// `(?=...)` or `(?!...)`
+    Lookahead { negated: bool },
+    /// `(?<=...)` or `(?<!...)`
+    Lookbehind { negated: bool },
+    /// `(?i:...)`
+    Scoped(Modifiers),
+}
+
+impl TryFrom<&tree::Node> for Node {
+    type Error = ParseError;
+
+    fn try_from(node: &tree::Node) -> Result<Self> {
+        let group =
+            |kind, content: &tree::Node| Ok(Node::Group(Group::new(kind, content.try_into()?)));
+        let repeat = |content: &tree::Node, quantifier, greed| {
+            let (min, max) = bounds(quantifier)?;
+            Ok(Node::Repeat(Repeat::new(
+                content.try_into()?,
+                min,
+                max,
+                greed,
+            )))
+        };
+        match node {
+            tree::Node::Empty => Ok(Node::Empty),
+            tree::Node::Sequence(sequence) => Ok(Node::Sequence(
+                sequence.iter().map(Node::try_from).collect::<Result<_>>()?,
+            )),
+            tree::Node::Tokens(tokens) => {
+                Ok(Node::Literal(tokens.iter().map(Token::to_char).collect()))
+            }
+            tree::Node::Capture(content) => group(GroupKind::Capturing { name: None }, content),
+            tree::Node::Group(kind, content) => match kind {
+                tree::group::Group::NonCapturing => group(GroupKind::NonCapturing, content),
+                tree::group::Group::Named(name, _) => group(
+                    GroupKind::Capturing {
+                        name: Some(name.clone()),
+                    },
+                    content,
+                ),
+                tree::group::Group::Atomic(_) => group(GroupKind::Atomic, content),
+                tree::group::Group::Lookahead { negated } => {
+                    group(GroupKind::Lookahead { negated: *negated }, content)
+                }
+                tree::group::Group::Lookbehind { negated } => {
+                    group(GroupKind::Lookbehind { negated: *negated }, content)
+                }
+                tree::group::Group::Scoped(modifiers) => {
+                    group(GroupKind::Scoped(modifiers.clone()), content)
+                }
+                tree::group::Group::Flags(modifiers) => Ok(Node::Flags(modifiers.clone())),
+                tree::group::Group::Reference(name) => {
+                    Ok(Node::Backreference(Reference::Name(name.clone())))
+                }
+            },
+            tree::Node::Special(Special::Dot) => Ok(Node::AnyCharacter),
+            tree::Node::Assertion(assertion, _) => Ok(Node::Assertion(*assertion)),
+            tree::Node::Class(class) => Ok(Node::Class(class.into())),
+            tree::Node::Augmented(content, quantifier) => {
+                repeat(content, quantifier, Greed::Greedy)
+            }
+            tree::Node::Lazy(content, quantifier, _) => repeat(content, quantifier, Greed::Lazy),
+            tree::Node::Possessive(content, quantifier, _) => {
+                repeat(content, quantifier, Greed::Possessive)
+            }
+            tree::Node::Alternation(alternatives) => Ok(Node::Alternation(
+                alternatives
+                    .iter()
+                    .map(Node::try_from)
+                    .collect::<Result<_>>()?,
+            )),
+            tree::Node::Backreference(reference, _) => Ok(Node::Backreference(reference.clone())),
+            tree::Node::Special(_) | tree::Node::Repetition(_) => Err(ParseError::internal(
+                format!("Found {node:?} where a whole node should be"),
+            )),
+        }
+    }
+}
+
+impl From<&CharacterClass> for Class {
+    fn from(class: &CharacterClass) -> Self {
+        let items = class
+            .items
+            .iter()
+            .map(|item| match item {
+                tree::class::ClassItem::Single(t) => ClassItem::Range(t.to_char(), t.to_char()),
+                tree::class::ClassItem::Range(a, b) => ClassItem::Range(a.to_char(), b.to_char()),
+                tree::class::ClassItem::Shorthand(shorthand) => ClassItem::Shorthand(*shorthand),
+                tree::class::ClassItem::Property(property) => ClassItem::Property {
+                    negated: property.negated,
+                    name: property.name.clone(),
+                },
+                tree::class::ClassItem::Named(named) => ClassItem::Named(*named),
+                tree::class::ClassItem::Equivalence(c) => ClassItem::Equivalence(*c),
+            })
+            .collect();
+        Class::new(class.negated, items)
+    }
+}
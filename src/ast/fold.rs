@@ -0,0 +1,140 @@
+use crate::ast::{Assertion, Class, Group, Modifiers, Node, Reference, Repeat};
+
+/// Rebuilds an AST, taking it apart and putting it back together.
+///
+/// Each kind of node has a method, which by default rebuilds the node from its folded children,
+/// so a transformation only needs to override the methods for the nodes it changes.  Override
+/// [`fold_node`](Fold::fold_node) to see every node before it's dispatched.
+pub trait Fold {
+    fn fold_node(&mut self, node: Node) -> Node {
+        match node {
+            Node::Empty => self.fold_empty(),
+            Node::Literal(text) => self.fold_literal(text),
+            Node::AnyCharacter => self.fold_any_character(),
+            Node::Class(class) => self.fold_class(class),
+            Node::Sequence(nodes) => self.fold_sequence(nodes),
+            Node::Alternation(nodes) => self.fold_alternation(nodes),
+            Node::Repeat(repeat) => self.fold_repeat(repeat),
+            Node::Group(group) => self.fold_group(group),
+            Node::Assertion(assertion) => self.fold_assertion(assertion),
+            Node::Backreference(reference) => self.fold_backreference(reference),
+            Node::Flags(modifiers) => self.fold_flags(modifiers),
+        }
+    }
+
+    fn fold_empty(&mut self) -> Node {
+        Node::Empty
+    }
+
+    fn fold_literal(&mut self, text: String) -> Node {
+        Node::Literal(text)
+    }
+
+    fn fold_any_character(&mut self) -> Node {
+        Node::AnyCharacter
+    }
+
+    fn fold_class(&mut self, class: Class) -> Node {
+        Node::Class(class)
+    }
+
+    fn fold_sequence(&mut self, nodes: Vec<Node>) -> Node {
+        Node::Sequence(nodes.into_iter().map(|n| self.fold_node(n)).collect())
+    }
+
+    fn fold_alternation(&mut self, nodes: Vec<Node>) -> Node {
+        Node::Alternation(nodes.into_iter().map(|n| self.fold_node(n)).collect())
+    }
+
+    fn fold_repeat(&mut self, repeat: Repeat) -> Node {
+        let node = self.fold_node(*repeat.node);
+        Node::Repeat(Repeat::new(node, repeat.min, repeat.max, repeat.greed))
+    }
+
+    fn fold_group(&mut self, group: Group) -> Node {
+        let node = self.fold_node(*group.node);
+        Node::Group(Group::new(group.kind, node))
+    }
+
+    fn fold_assertion(&mut self, assertion: Assertion) -> Node {
+        Node::Assertion(assertion)
+    }
+
+    fn fold_backreference(&mut self, reference: Reference) -> Node {
+        Node::Backreference(reference)
+    }
+
+    fn fold_flags(&mut self, modifiers: Modifiers) -> Node {
+        Node::Flags(modifiers)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod test {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::ast::{Fold, Node, Repeat};
+    use crate::{Alphabet, Flags, Pattern};
+
+    /// Limits unbounded repetitions to at most the given number
+    #[derive(Debug)]
+    struct Bound(u32);
+
+    impl Fold for Bound {
+        fn fold_repeat(&mut self, repeat: Repeat) -> Node {
+            let node = self.fold_node(*repeat.node);
+            let max = repeat.max.unwrap_or(self.0.max(repeat.min));
+            Node::Repeat(Repeat::new(node, repeat.min, Some(max), repeat.greed))
+        }
+    }
+
+    #[rstest]
+    #[case("a*", vec!["", "a", "aa"])]
+    #[case("(b+|c)d", vec!["bd", "bbd", "cd"])]
+    #[case("x{3,}", vec!["xxx"])]
+    fn test_fold(#[case] input: &str, #[case] expected: Vec<&str>) {
+        let pattern = Pattern::parse(input).expect("Failed to parse");
+        let ast = Bound(2).fold_node(pattern.ast().clone());
+        let bounded = Pattern::from_ast(&ast, &Alphabet::default(), Flags::default())
+            .expect("Failed to build pattern");
+        assert_eq!(expected, bounded.members().collect::<Vec<_>>());
+    }
+
+    #[rstest]
+    fn test_identity() {
+        #[derive(Debug)]
+        struct Identity;
+        impl Fold for Identity {}
+
+        let pattern = Pattern::parse("(?i)^(?<x>a[^b-d\\W]|x\\k<x>.+?)(?=[a-c])\\b\\p{L}?")
+            .expect("Failed to parse");
+        let ast = Identity.fold_node(pattern.ast().clone());
+        assert_eq!(pattern.ast(), &ast);
+        let rebuilt = Pattern::from_ast(&ast, &Alphabet::default(), Flags::default())
+            .expect("Failed to build pattern");
+        assert_eq!(
+            pattern.members().take(20).collect::<Vec<_>>(),
+            rebuilt.members().take(20).collect::<Vec<_>>()
+        );
+    }
+
+    #[rstest]
+    #[case("(?>a|ab)c(?>d|de)e")]
+    #[case("a*?b(?<x>c)\\k<x>++")]
+    fn test_analyse_round_trip(#[case] input: &str) {
+        #[derive(Debug)]
+        struct Identity;
+        impl Fold for Identity {}
+
+        let pattern = Pattern::parse(input).expect("Failed to parse");
+        let ast = Identity.fold_node(pattern.ast().clone());
+        let rebuilt = Pattern::from_ast(&ast, &Alphabet::default(), Flags::default())
+            .expect("Failed to build pattern");
+        assert_eq!(
+            pattern.analyse().expect("Failed to analyse"),
+            rebuilt.analyse().expect("Failed to analyse")
+        );
+    }
+}
@@ -0,0 +1,593 @@
+use crate::ast::{
+    Assertion, Class, ClassItem, Flag, Greed, Group, GroupKind, Modifiers, Node, Reference, Repeat,
+};
+use crate::dialect::{Dialect, Feature};
+use crate::error::{ParseError, Result};
+use crate::tokens::TokenMeta;
+
+/// Where errors about an AST say they are, since it isn't in any text
+const NOWHERE: TokenMeta = TokenMeta { position: 0 };
+
+impl Node {
+    /// Writes the node as an expression in `dialect`, with as few parentheses as it needs, so
+    /// that parsing the result in `dialect` gives the node back.
+    ///
+    /// The one exception is a repetition of a repetition, like `a{2}{3}`, which most engines
+    /// reject, so it's written as `(?:a{2}){3}` instead.  Fails if `dialect` can't express part of
+    /// the node, like a lookbehind in Go.
+    ///
+    /// ```
+    /// use regular::{Dialect, Pattern};
+    ///
+    /// let pattern = Pattern::parse("(a|b)+c{2}").unwrap();
+    /// let text = pattern.ast().to_pattern(Dialect::PosixBasic).unwrap();
+    /// assert_eq!("\\(a\\|b\\)\\+c\\{2\\}", text);
+    /// ```
+    pub fn to_pattern(&self, dialect: Dialect) -> std::result::Result<String, ParseError> {
+        Printer {
+            dialect,
+            extended: false,
+        }
+        .node(self, Context::Alternative)
+    }
+}
+
+/// What a node is part of, which decides whether it needs parentheses to stay in one piece
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Context {
+    /// One of the alternatives of an alternation, or the whole expression
+    Alternative,
+    /// One of the nodes of a sequence
+    Sequence,
+    /// What a quantifier repeats
+    Quantified,
+}
+
+#[derive(Debug)]
+struct Printer {
+    dialect: Dialect,
+    /// Whether extended mode is on where we're writing, so whitespace and `#` need escaping
+    extended: bool,
+}
+
+impl Printer {
+    fn node(&mut self, node: &Node, context: Context) -> Result<String> {
+        let quantified = context == Context::Quantified;
+        match node {
+            Node::Empty if quantified => self.wrap(|_| Ok(String::new())),
+            Node::Empty => Ok(String::new()),
+            Node::Literal(text) => match text.chars().count() {
+                0 => self.node(&Node::Empty, context),
+                1 => Ok(self.literal(text)),
+                _ if quantified => self.wrap(|p| Ok(p.literal(text))),
+                _ => Ok(self.literal(text)),
+            },
+            Node::AnyCharacter => Ok(".".to_string()),
+            Node::Class(class) => self.class(class),
+            Node::Sequence(nodes) => match nodes.as_slice() {
+                [] => self.node(&Node::Empty, context),
+                [node] => self.node(node, context),
+                _ if quantified => self.wrap(|p| p.sequence(nodes)),
+                _ => self.sequence(nodes),
+            },
+            Node::Alternation(nodes) => match nodes.as_slice() {
+                [] => self.node(&Node::Empty, context),
+                [node] => self.node(node, context),
+                _ if context != Context::Alternative => self.wrap(|p| p.alternation(nodes)),
+                _ => self.alternation(nodes),
+            },
+            Node::Repeat(repeat) if quantified => self.wrap(|p| p.repeat(repeat)),
+            Node::Repeat(repeat) => self.repeat(repeat),
+            Node::Group(group) => self.group(group),
+            Node::Assertion(assertion) if quantified => self.wrap(|p| p.assertion(*assertion)),
+            Node::Assertion(assertion) => self.assertion(*assertion),
+            Node::Backreference(reference) => self.backreference(reference),
+            Node::Flags(modifiers) if modifiers.on.is_empty() && modifiers.off.is_empty() => {
+                self.node(&Node::Empty, context)
+            }
+            Node::Flags(modifiers) if quantified => self.wrap(|p| p.flags(modifiers)),
+            Node::Flags(modifiers) => self.flags(modifiers),
+        }
+    }
+
+    /// Puts what `write` writes in a non-capturing group, which keeps any flags it changes inside
+    fn wrap(&mut self, write: impl FnOnce(&mut Self) -> Result<String>) -> Result<String> {
+        self.dialect.check(Feature::NonCapturingGroup, NOWHERE)?;
+        let extended = self.extended;
+        let content = write(self);
+        self.extended = extended;
+        Ok(format!("(?:{})", content?))
+    }
+
+    fn sequence(&mut self, nodes: &[Node]) -> Result<String> {
+        let mut text = String::new();
+        let mut previous: Option<&Node> = None;
+        let mut dollar = false;
+        for node in nodes {
+            let part = self.node(node, Context::Sequence)?;
+            // POSIX basic expressions read `^` and `$` as literals away from the ends of a sequence
+            let caret = matches!(first(node), Node::Assertion(Assertion::Caret));
+            if (caret && !text.is_empty()) || (dollar && !part.is_empty()) {
+                self.dialect.check(Feature::InnerAnchor, NOWHERE)?;
+            }
+            if !part.is_empty() {
+                dollar = matches!(last(node), Node::Assertion(Assertion::Dollar));
+            }
+            // A digit straight after `\1` would make it a different backreference
+            if let Some(Node::Backreference(reference @ Reference::Number(_))) = previous.map(last)
+            {
+                if part.starts_with(|c: char| c.is_ascii_digit()) {
+                    let reference = self.backreference(reference)?;
+                    text.truncate(text.len() - reference.len());
+                    text += &self.wrap(|_| Ok(reference))?;
+                }
+            }
+            text += &part;
+            previous = Some(node);
+        }
+        Ok(text)
+    }
+
+    fn alternation(&mut self, nodes: &[Node]) -> Result<String> {
+        let alternatives = nodes
+            .iter()
+            .map(|node| self.node(node, Context::Alternative))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(alternatives.join(&self.special("|")))
+    }
+
+    fn repeat(&mut self, repeat: &Repeat) -> Result<String> {
+        let (min, max) = (repeat.min, repeat.max);
+        let quantifier = match (min, max) {
+            (_, Some(max)) if max < min => {
+                return Err(ParseError::RepetitionOutOfOrder {
+                    text: format!("{min},{max}"),
+                    span: NOWHERE.into(),
+                })
+            }
+            (0, None) => "*".to_string(),
+            (1, None) => "+".to_string(),
+            (0, Some(1)) => "?".to_string(),
+            (min, None) => format!("{{{min},}}"),
+            (min, Some(max)) if min == max => format!("{{{min}}}"),
+            (min, Some(max)) => format!("{{{min},{max}}}"),
+        };
+        let greed = match repeat.greed {
+            Greed::Greedy => "",
+            Greed::Lazy => {
+                self.dialect.check(Feature::LazyQuantifier, NOWHERE)?;
+                "?"
+            }
+            Greed::Possessive => {
+                self.dialect.check(Feature::PossessiveQuantifier, NOWHERE)?;
+                "+"
+            }
+        };
+        let content = self.node(&repeat.node, Context::Quantified)?;
+        Ok(format!("{content}{}{greed}", self.special(&quantifier)))
+    }
+
+    fn group(&mut self, group: &Group) -> Result<String> {
+        let (open, feature) = match &group.kind {
+            GroupKind::Capturing { name: None } => (self.special("("), None),
+            GroupKind::Capturing { name: Some(name) } => {
+                let p = match self.dialect {
+                    Dialect::Python | Dialect::Go | Dialect::Rust => "P",
+                    _ => "",
+                };
+                (format!("(?{p}<{name}>"), Some(Feature::NamedGroup))
+            }
+            GroupKind::NonCapturing => ("(?:".to_string(), Some(Feature::NonCapturingGroup)),
+            GroupKind::Atomic => ("(?>".to_string(), Some(Feature::AtomicGroup)),
+            GroupKind::Lookahead { negated } => (
+                if *negated { "(?!" } else { "(?=" }.to_string(),
+                Some(Feature::Lookahead),
+            ),
+            GroupKind::Lookbehind { negated } => (
+                if *negated { "(?<!" } else { "(?<=" }.to_string(),
+                Some(Feature::Lookbehind),
+            ),
+            GroupKind::Scoped(modifiers) => (
+                format!("(?{}:", letters(modifiers)),
+                Some(Feature::InlineFlags),
+            ),
+        };
+        if let Some(feature) = feature {
+            self.dialect.check(feature, NOWHERE)?;
+        }
+        let extended = self.extended;
+        if let GroupKind::Scoped(modifiers) = &group.kind {
+            self.switch(modifiers);
+        }
+        let content = self.node(&group.node, Context::Alternative);
+        self.extended = extended;
+        Ok(format!("{open}{}{}", content?, self.special(")")))
+    }
+
+    fn assertion(&self, assertion: Assertion) -> Result<String> {
+        let feature = match assertion {
+            Assertion::Caret | Assertion::Dollar => None,
+            Assertion::StartOfText => Some(Feature::StartOfText),
+            Assertion::EndOfText => Some(Feature::EndOfText),
+            Assertion::EndOfTextOrNewline => Some(Feature::EndOfTextOrNewline),
+            Assertion::WordBoundary | Assertion::NotWordBoundary => Some(Feature::WordBoundary),
+        };
+        if let Some(feature) = feature {
+            self.dialect.check(feature, NOWHERE)?;
+        }
+        if self.dialect == Dialect::Python && assertion == Assertion::EndOfText {
+            return Ok("\\Z".to_string());
+        }
+        Ok(assertion.to_string())
+    }
+
+    fn backreference(&self, reference: &Reference) -> Result<String> {
+        match reference {
+            Reference::Number(number) => {
+                self.dialect.check(Feature::Backreference, NOWHERE)?;
+                Ok(format!("\\{number}"))
+            }
+            Reference::Name(name) => {
+                self.dialect.check(Feature::NamedBackreference, NOWHERE)?;
+                Ok(match self.dialect {
+                    Dialect::Python => format!("(?P={name})"),
+                    _ => format!("\\k<{name}>"),
+                })
+            }
+        }
+    }
+
+    fn flags(&mut self, modifiers: &Modifiers) -> Result<String> {
+        self.dialect.check(Feature::InlineFlags, NOWHERE)?;
+        self.switch(modifiers);
+        Ok(format!("(?{})", letters(modifiers)))
+    }
+
+    /// Keeps track of extended mode as flags switch it on and off
+    fn switch(&mut self, modifiers: &Modifiers) {
+        if modifiers.on.contains(&Flag::Extended) {
+            self.extended = true;
+        }
+        if modifiers.off.contains(&Flag::Extended) {
+            self.extended = false;
+        }
+    }
+
+    fn class(&self, class: &Class) -> Result<String> {
+        match (class.negated, class.items.as_slice()) {
+            // A shorthand or property on its own doesn't need brackets
+            (false, [item @ (ClassItem::Shorthand(_) | ClassItem::Property { .. })]) => {
+                return self.class_item(item)
+            }
+            // Brackets can't be empty, so match everything or nothing another way
+            (negated, []) => {
+                self.dialect.check(Feature::ShorthandClass, NOWHERE)?;
+                return Ok(if negated { "[\\s\\S]" } else { "[^\\s\\S]" }.to_string());
+            }
+            _ => {}
+        }
+        let mut text = String::from(if class.negated { "[^" } else { "[" });
+        if !self.posix() {
+            for item in &class.items {
+                text += &self.class_item(item)?;
+            }
+            text.push(']');
+            return Ok(text);
+        }
+        // POSIX has no escapes in brackets, so `]` has to come first and `-` last to be literal,
+        // and ranges that start or end with them are split so that they can
+        let (mut close, mut dash) = (false, false);
+        let mut items = vec![];
+        for item in &class.items {
+            let (mut start, mut end) = match item {
+                ClassItem::Range(start, end) if start <= end => (*start, *end),
+                _ => {
+                    items.push(item.clone());
+                    continue;
+                }
+            };
+            // Both are ASCII, so the characters either side of them are too
+            for (c, seen) in [(']', &mut close), ('-', &mut dash)] {
+                if start == c {
+                    *seen = true;
+                    start = char::from(c as u8 + 1);
+                }
+                if end == c && start <= end {
+                    *seen = true;
+                    end = char::from(c as u8 - 1);
+                }
+            }
+            if start <= end {
+                items.push(ClassItem::Range(start, end));
+            }
+        }
+        if close {
+            text.push(']');
+        }
+        for item in &items {
+            text += &self.class_item(item)?;
+        }
+        if dash {
+            text.push('-');
+        }
+        text.push(']');
+        Ok(text)
+    }
+
+    fn class_item(&self, item: &ClassItem) -> Result<String> {
+        Ok(match item {
+            ClassItem::Range(a, b) if a > b => {
+                return Err(ParseError::InvalidRange {
+                    range: format!("{a}-{b}"),
+                    span: NOWHERE.into(),
+                })
+            }
+            ClassItem::Range(a, b) if a == b => self.class_character(*a),
+            ClassItem::Range(a, b) => {
+                format!("{}-{}", self.class_character(*a), self.class_character(*b))
+            }
+            ClassItem::Shorthand(shorthand) => {
+                self.dialect.check(Feature::ShorthandClass, NOWHERE)?;
+                shorthand.to_string()
+            }
+            ClassItem::Property { negated, name } => {
+                self.dialect.check(Feature::UnicodeProperty, NOWHERE)?;
+                format!("\\{}{{{name}}}", if *negated { 'P' } else { 'p' })
+            }
+            ClassItem::Named(named) => {
+                self.dialect.check(Feature::PosixClass, NOWHERE)?;
+                named.to_string()
+            }
+            ClassItem::Equivalence(c) => {
+                self.dialect.check(Feature::CollatingElement, NOWHERE)?;
+                format!("[={c}=]")
+            }
+        })
+    }
+
+    fn literal(&self, text: &str) -> String {
+        text.chars().map(|c| self.character(c)).collect()
+    }
+
+    /// Writes a character outside brackets so that it stands for itself
+    fn character(&self, c: char) -> String {
+        let special = match self.dialect {
+            Dialect::PosixBasic => "\\^$.*[]",
+            _ => "\\^$.|?*+()[]{}",
+        };
+        match self.control(c) {
+            Some(escape) => escape,
+            None if special.contains(c) => format!("\\{c}"),
+            None if self.extended && (c.is_whitespace() || c == '#') => format!("\\{c}"),
+            None => c.to_string(),
+        }
+    }
+
+    /// Writes a character inside brackets so that it stands for itself
+    fn class_character(&self, c: char) -> String {
+        if self.posix() {
+            return match c {
+                '[' | '^' => format!("[.{c}.]"),
+                c => c.to_string(),
+            };
+        }
+        // Rust and Python read doubled `&`, `~` and `|` as operations on sets
+        let special = match self.dialect {
+            Dialect::Rust | Dialect::Python => "\\[]^-&~|",
+            _ => "\\[]^-",
+        };
+        match self.control(c) {
+            Some(escape) => escape,
+            None if special.contains(c) => format!("\\{c}"),
+            None => c.to_string(),
+        }
+    }
+
+    /// The escape for a control character, in dialects that have escapes for them
+    fn control(&self, c: char) -> Option<String> {
+        if self.posix() {
+            return None;
+        }
+        match c {
+            '\t' => Some("\\t".to_string()),
+            '\n' => Some("\\n".to_string()),
+            '\x0B' => Some("\\v".to_string()),
+            '\x0C' => Some("\\f".to_string()),
+            '\r' => Some("\\r".to_string()),
+            c if c.is_control() && c < '\u{100}' => Some(format!("\\x{:02X}", u32::from(c))),
+            _ => None,
+        }
+    }
+
+    /// Writes syntax the way PCRE has it, escaping what POSIX basic expressions swap round
+    fn special(&self, text: &str) -> String {
+        match self.dialect {
+            Dialect::PosixBasic => text
+                .chars()
+                .map(|c| match c {
+                    '(' | ')' | '{' | '}' | '|' | '+' | '?' => format!("\\{c}"),
+                    c => c.to_string(),
+                })
+                .collect(),
+            _ => text.to_string(),
+        }
+    }
+
+    fn posix(&self) -> bool {
+        self.dialect.is_posix()
+    }
+}
+
+/// The letters of a flag group, like `im-s`
+fn letters(modifiers: &Modifiers) -> String {
+    let on: String = modifiers.on.iter().map(|f| f.letter()).collect();
+    let off: String = modifiers.off.iter().map(|f| f.letter()).collect();
+    if off.is_empty() {
+        on
+    } else {
+        format!("{on}-{off}")
+    }
+}
+
+/// The node whose text comes first in a node's text
+fn first(node: &Node) -> &Node {
+    match node {
+        Node::Sequence(nodes) => nodes.first().map_or(node, first),
+        _ => node,
+    }
+}
+
+/// The node whose text comes last in a node's text
+fn last(node: &Node) -> &Node {
+    match node {
+        Node::Sequence(nodes) => nodes.last().map_or(node, last),
+        _ => node,
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod test {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::ast::{Greed, Node, Reference, Repeat};
+    use crate::{Alphabet, Dialect, Flags, Pattern};
+
+    fn parse(input: &str, dialect: Dialect) -> Pattern {
+        let flags = Flags {
+            dialect,
+            ..Flags::default()
+        };
+        Pattern::parse_with(input, &Alphabet::default(), flags).expect("Failed to parse")
+    }
+
+    #[rstest]
+    #[case(Dialect::Pcre, "abc")]
+    #[case(Dialect::Pcre, "a|b|")]
+    #[case(Dialect::Pcre, "(a|bc)*d")]
+    #[case(Dialect::Pcre, "ab*c+?d?+e{2}f{2,}g{2,5}")]
+    #[case(Dialect::Pcre, "(?:ab)*")]
+    #[case(Dialect::Pcre, "()")]
+    #[case(Dialect::Pcre, "\\.\\*\\(\\)\\[\\]\\{\\}\\|\\?\\+\\^\\$\\\\")]
+    #[case(Dialect::Pcre, "\\t\\n\\x00\\x7F")]
+    #[case(Dialect::Pcre, "^\\A\\b\\B\\z\\Z$")]
+    #[case(Dialect::Pcre, "[^a-z_\\d\\p{Lu}][\\]\\[\\-\\^\\\\]")]
+    #[case(Dialect::Pcre, "\\d\\W\\P{L}.")]
+    #[case(Dialect::Pcre, "[[:alpha:][=e=]]")]
+    #[case(Dialect::Pcre, "(?<x>a)\\k<x>(b)\\2")]
+    #[case(Dialect::Pcre, "(?>a)(?=b)(?!c)(?<=d)(?<!e)")]
+    #[case(Dialect::Pcre, "(?i)a(?m-s:b)")]
+    #[case(Dialect::Pcre, "(?x)a\\ b\\#c(?-x: #)")]
+    #[case(Dialect::PosixBasic, "\\(a\\|b\\)\\+c\\{2\\}{(|)}+?\\1")]
+    #[case(Dialect::PosixBasic, "[]a[.^.][.[.]\\-]")]
+    #[case(Dialect::PosixBasic, "^\\^a\\$$\\|^\\(^b$\\)$")]
+    #[case(Dialect::PosixExtended, "[]a-c-][^]-][][.^.]-]")]
+    #[case(Dialect::PosixExtended, "[]!-\\][.-/-]")]
+    #[case(Dialect::PosixExtended, "(a|b)+\\{[^]x]")]
+    #[case(Dialect::JavaScript, "(?<x>a)\\k<x>")]
+    #[case(Dialect::Python, "(?P<x>a)(?P=x)[\\&\\&]")]
+    #[case(Dialect::Python, "\\Aa\\Z")]
+    #[case(Dialect::Go, "(?P<x>a)(?i:b)*?")]
+    #[case(Dialect::Rust, "[a\\-\\~\\~]")]
+    fn test_round_trip(#[case] dialect: Dialect, #[case] input: &str) {
+        let pattern = parse(input, dialect);
+        let text = pattern.ast().to_pattern(dialect).expect("Failed to print");
+        assert_eq!(input, text);
+        assert_eq!(pattern.ast(), parse(&text, dialect).ast());
+    }
+
+    #[rstest]
+    #[case("(a|b)+c{2}", Dialect::PosixBasic, "\\(a\\|b\\)\\+c\\{2\\}")]
+    #[case("(?P<x>a)(?P=x)", Dialect::Pcre, "(?<x>a)\\k<x>")]
+    #[case("(?<x>a)\\k<x>", Dialect::Python, "(?P<x>a)(?P=x)")]
+    #[case("[\\-^\\]]", Dialect::PosixExtended, "[][.^.]-]")]
+    #[case("[!-\\]\\--/]", Dialect::PosixExtended, "[]!-\\.-/-]")]
+    #[case("a{0,1}b{1,}", Dialect::Pcre, "a?b+")]
+    #[case("(?i)\\x41", Dialect::Rust, "(?i)A")]
+    #[case("a\\z", Dialect::Python, "a\\Z")]
+    fn test_translate(#[case] input: &str, #[case] dialect: Dialect, #[case] expected: &str) {
+        let text = Pattern::parse(input)
+            .expect("Failed to parse")
+            .ast()
+            .to_pattern(dialect)
+            .expect("Failed to print");
+        assert_eq!(expected, text);
+    }
+
+    fn repeat(node: Node, min: u32, max: Option<u32>) -> Node {
+        Node::Repeat(Repeat::new(node, min, max, Greed::Greedy))
+    }
+
+    fn literal(text: &str) -> Node {
+        Node::Literal(text.to_string())
+    }
+
+    fn sequence(nodes: Vec<Node>) -> Node {
+        Node::Sequence(nodes)
+    }
+
+    fn alternation(nodes: Vec<Node>) -> Node {
+        Node::Alternation(nodes)
+    }
+
+    #[rstest]
+    #[case(repeat(literal("ab"), 0, None), "(?:ab)*")]
+    #[case(repeat(repeat(literal("a"), 2, Some(2)), 3, Some(3)), "(?:a{2}){3}")]
+    #[case(repeat(Node::Empty, 1, None), "(?:)+")]
+    #[case(sequence(vec![alternation(vec![literal("a"), literal("b")]), literal("c")]), "(?:a|b)c")]
+    #[case(sequence(vec![literal("a"), sequence(vec![literal("b"), literal("c")])]), "abc")]
+    #[case(alternation(vec![literal("a"), alternation(vec![literal("b"), literal("c")])]), "a|b|c")]
+    #[case(sequence(vec![Node::Backreference(Reference::Number(1)), literal("2")]), "(?:\\1)2")]
+    fn test_parentheses(#[case] node: Node, #[case] expected: &str) {
+        assert_eq!(
+            expected,
+            node.to_pattern(Dialect::Pcre).expect("Failed to print")
+        );
+    }
+
+    #[rstest]
+    #[case(
+        "(?<=a)",
+        Dialect::Go,
+        "Lookbehind at position 0 isn't supported in Go"
+    )]
+    #[case(
+        "a*?",
+        Dialect::PosixExtended,
+        "Lazy quantifier at position 0 isn't supported in POSIX extended regular expressions"
+    )]
+    #[case(
+        "(?:a)",
+        Dialect::PosixBasic,
+        "Non-capturing group at position 0 isn't supported in POSIX basic regular expressions"
+    )]
+    #[case(
+        "\\d",
+        Dialect::PosixExtended,
+        "Shorthand class at position 0 isn't supported in POSIX extended regular expressions"
+    )]
+    #[case(
+        "a^b",
+        Dialect::PosixBasic,
+        "Anchor in the middle of a sequence at position 0 isn't supported in POSIX basic regular expressions"
+    )]
+    #[case(
+        "a$b",
+        Dialect::PosixBasic,
+        "Anchor in the middle of a sequence at position 0 isn't supported in POSIX basic regular expressions"
+    )]
+    #[case(
+        "a\\Z",
+        Dialect::Python,
+        "End of text or final newline anchor at position 0 isn't supported in Python"
+    )]
+    fn test_unsupported(#[case] input: &str, #[case] dialect: Dialect, #[case] expected: &str) {
+        let error = Pattern::parse(input)
+            .expect("Failed to parse")
+            .ast()
+            .to_pattern(dialect)
+            .expect_err("Should not print");
+        assert_eq!(expected, error.to_string());
+    }
+}
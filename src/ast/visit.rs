@@ -0,0 +1,92 @@
+use crate::ast::{Assertion, Class, Group, Modifiers, Node, Reference, Repeat};
+
+/// Walks an AST without changing it.
+///
+/// Each kind of node has a method, which by default visits the node's children, so an analysis
+/// only needs to override the methods for the nodes it's interested in.  Override
+/// [`visit_node`](Visitor::visit_node) to see every node before it's dispatched.
+pub trait Visitor {
+    fn visit_node(&mut self, node: &Node) {
+        match node {
+            Node::Empty => self.visit_empty(),
+            Node::Literal(text) => self.visit_literal(text),
+            Node::AnyCharacter => self.visit_any_character(),
+            Node::Class(class) => self.visit_class(class),
+            Node::Sequence(nodes) => self.visit_sequence(nodes),
+            Node::Alternation(nodes) => self.visit_alternation(nodes),
+            Node::Repeat(repeat) => self.visit_repeat(repeat),
+            Node::Group(group) => self.visit_group(group),
+            Node::Assertion(assertion) => self.visit_assertion(*assertion),
+            Node::Backreference(reference) => self.visit_backreference(reference),
+            Node::Flags(modifiers) => self.visit_flags(modifiers),
+        }
+    }
+
+    fn visit_empty(&mut self) {}
+
+    fn visit_literal(&mut self, _text: &str) {}
+
+    fn visit_any_character(&mut self) {}
+
+    fn visit_class(&mut self, _class: &Class) {}
+
+    fn visit_sequence(&mut self, nodes: &[Node]) {
+        for node in nodes {
+            self.visit_node(node);
+        }
+    }
+
+    fn visit_alternation(&mut self, nodes: &[Node]) {
+        for node in nodes {
+            self.visit_node(node);
+        }
+    }
+
+    fn visit_repeat(&mut self, repeat: &Repeat) {
+        self.visit_node(&repeat.node);
+    }
+
+    fn visit_group(&mut self, group: &Group) {
+        self.visit_node(&group.node);
+    }
+
+    fn visit_assertion(&mut self, _assertion: Assertion) {}
+
+    fn visit_backreference(&mut self, _reference: &Reference) {}
+
+    fn visit_flags(&mut self, _modifiers: &Modifiers) {}
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod test {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::ast::{Group, GroupKind, Visitor};
+    use crate::Pattern;
+
+    /// Collects the names of capture groups, in order
+    #[derive(Debug, Default)]
+    struct Names(Vec<Option<String>>);
+
+    impl Visitor for Names {
+        fn visit_group(&mut self, group: &Group) {
+            if let GroupKind::Capturing { name } = &group.kind {
+                self.0.push(name.clone());
+            }
+            self.visit_node(&group.node);
+        }
+    }
+
+    #[rstest]
+    #[case("(a)(?:b)(?<x>c(d))", vec![None, Some("x"), None])]
+    #[case("a|(b)*|(?=(?<y>c))", vec![None, Some("y")])]
+    fn test_visit(#[case] input: &str, #[case] expected: Vec<Option<&str>>) {
+        let pattern = Pattern::parse(input).expect("Failed to parse");
+        let mut names = Names::default();
+        names.visit_node(pattern.ast());
+        let expected: Vec<_> = expected.into_iter().map(|n| n.map(String::from)).collect();
+        assert_eq!(expected, names.0);
+    }
+}
@@ -0,0 +1,289 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::error::{ParseError, Result, Span, ValueError};
+use crate::tokens::{Token, TokenMeta};
+use crate::tree::class::{ClassItem, PosixBracket};
+use crate::tree::group::Group;
+
+/// The regular expression syntax to read an expression as
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum Dialect {
+    /// Perl-compatible regular expressions, which support everything
+    #[default]
+    Pcre,
+    /// POSIX basic regular expressions, as used by `grep` and `sed`, where `\(`, `\{`, `\|`, `\+`
+    /// and `\?` are special and `(`, `{`, `|`, `+` and `?` are literal
+    PosixBasic,
+    /// POSIX extended regular expressions, as used by `grep -E`
+    PosixExtended,
+    /// JavaScript's `RegExp`
+    JavaScript,
+    /// Python's `re` module
+    Python,
+    /// Go's `regexp` package, which uses the RE2 syntax
+    Go,
+    /// Rust's `regex` crate
+    Rust,
+}
+
+/// A part of the syntax that only some dialects support
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum Feature {
+    NonCapturingGroup,
+    NamedGroup,
+    InlineFlags,
+    LazyQuantifier,
+    PossessiveQuantifier,
+    AtomicGroup,
+    Lookahead,
+    Lookbehind,
+    Backreference,
+    NamedBackreference,
+    ShorthandClass,
+    UnicodeProperty,
+    WordBoundary,
+    /// `\A`
+    StartOfText,
+    /// `\z`, which Python writes as `\Z`
+    EndOfText,
+    /// `\Z`, which also matches before a newline at the end of the text
+    EndOfTextOrNewline,
+    /// `^` after the start of a sequence or `$` before its end, which POSIX basic expressions
+    /// read as literal characters
+    InnerAnchor,
+    PosixClass,
+    CollatingElement,
+}
+
+impl Dialect {
+    pub fn supports(self, feature: Feature) -> bool {
+        use Feature::*;
+        match self {
+            Dialect::Pcre => true,
+            Dialect::PosixBasic => matches!(feature, Backreference | PosixClass | CollatingElement),
+            Dialect::PosixExtended => {
+                matches!(feature, InnerAnchor | PosixClass | CollatingElement)
+            }
+            Dialect::JavaScript => !matches!(
+                feature,
+                InlineFlags
+                    | PossessiveQuantifier
+                    | AtomicGroup
+                    | StartOfText
+                    | EndOfText
+                    | EndOfTextOrNewline
+                    | PosixClass
+                    | CollatingElement
+            ),
+            Dialect::Python => !matches!(
+                feature,
+                UnicodeProperty | EndOfTextOrNewline | PosixClass | CollatingElement
+            ),
+            Dialect::Go | Dialect::Rust => matches!(
+                feature,
+                NonCapturingGroup
+                    | NamedGroup
+                    | InlineFlags
+                    | LazyQuantifier
+                    | ShorthandClass
+                    | UnicodeProperty
+                    | WordBoundary
+                    | StartOfText
+                    | EndOfText
+                    | InnerAnchor
+                    | PosixClass
+            ),
+        }
+    }
+
+    /// Whether this is one of the POSIX dialects, where `\` has no special meaning in a bracket
+    /// expression
+    pub(crate) fn is_posix(self) -> bool {
+        matches!(self, Dialect::PosixBasic | Dialect::PosixExtended)
+    }
+
+    /// Rejects the feature at `meta` if this dialect doesn't support it
+    pub(crate) fn check(self, feature: Feature, meta: TokenMeta) -> Result<()> {
+        if self.supports(feature) {
+            Ok(())
+        } else {
+            Err(ParseError::Unsupported {
+                feature,
+                dialect: self,
+                span: Span::from(meta),
+            })
+        }
+    }
+
+    /// Rejects the feature that the token stands for, if it stands for one this dialect doesn't
+    /// support
+    pub(crate) fn check_token(self, token: &Token) -> Result<()> {
+        let feature = match token {
+            Token::Escaped('d' | 'D' | 'w' | 'W' | 's' | 'S', _) => Feature::ShorthandClass,
+            Token::Escaped('b' | 'B', _) => Feature::WordBoundary,
+            Token::Escaped('A', _) => Feature::StartOfText,
+            // Python has no `\z`, and its `\Z` is PCRE's `\z`
+            Token::Escaped('z', _) if self == Dialect::Python => {
+                return Err(ParseError::UnexpectedToken {
+                    token: "\\z".to_string(),
+                    span: Span::new(token.position(), token.position() + 2),
+                })
+            }
+            Token::Escaped('Z', _) if self == Dialect::Python => Feature::EndOfText,
+            Token::Escaped('z', _) => Feature::EndOfText,
+            Token::Escaped('Z', _) => Feature::EndOfTextOrNewline,
+            Token::Property(..) => Feature::UnicodeProperty,
+            Token::Backreference(name, _) if name.starts_with(|c: char| c.is_ascii_digit()) => {
+                Feature::Backreference
+            }
+            Token::Backreference(..) => Feature::NamedBackreference,
+            _ => return Ok(()),
+        };
+        self.check(
+            feature,
+            TokenMeta {
+                position: token.position(),
+            },
+        )
+    }
+
+    /// Rejects the group if this dialect doesn't support it or the way it's written, given the
+    /// tokens of its header after `(?` and the position of its opening parenthesis
+    pub(crate) fn check_group(
+        self,
+        group: &Group,
+        header: &[Token],
+        meta: TokenMeta,
+    ) -> Result<()> {
+        self.check(Feature::from(group), meta)?;
+        // Python only names groups with `(?P<name>...)`
+        if let (Dialect::Python, Group::Named(..)) = (self, group) {
+            if !matches!(header.first(), Some(Token::Raw('P', _))) {
+                let end = header.last().map_or(meta.position, Token::position) + 1;
+                return Err(ParseError::UnknownGroup {
+                    syntax: header.iter().map(Token::to_char).collect(),
+                    span: Span::new(meta.position, end),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Rejects the first thing in a class body that this dialect doesn't support
+    pub(crate) fn check_class(self, mut body: &[Token]) -> Result<()> {
+        while let Some((first, rest)) = body.split_first() {
+            if let Some((bracket, span, rest)) = PosixBracket::split(body) {
+                let feature = match bracket {
+                    PosixBracket::Named(_) => Feature::PosixClass,
+                    PosixBracket::Equivalence(_) | PosixBracket::Collating(_) => {
+                        Feature::CollatingElement
+                    }
+                };
+                let meta = TokenMeta {
+                    position: span.start,
+                };
+                self.check(feature, meta)?;
+                body = rest;
+                continue;
+            }
+            if ClassItem::for_set(first)?.is_some() {
+                self.check_token(first)?;
+            }
+            body = rest;
+        }
+        Ok(())
+    }
+
+    /// Swaps escaped and unescaped metacharacters where this dialect has them the other way round
+    /// from PCRE, so that the rest of the parser sees them the PCRE way
+    pub(crate) fn translate(self, token: Token) -> Token {
+        match (self, token) {
+            (
+                Dialect::PosixBasic,
+                Token::Escaped(c @ ('(' | ')' | '{' | '}' | '|' | '+' | '?'), m),
+            ) => Token::Raw(c, m),
+            (Dialect::PosixBasic, Token::Raw(c @ ('(' | ')' | '{' | '}' | '|' | '+' | '?'), m)) => {
+                Token::Escaped(c, m)
+            }
+            (_, token) => token,
+        }
+    }
+}
+
+impl From<&Group> for Feature {
+    fn from(group: &Group) -> Self {
+        match group {
+            Group::NonCapturing => Feature::NonCapturingGroup,
+            Group::Named(..) => Feature::NamedGroup,
+            Group::Atomic(_) => Feature::AtomicGroup,
+            Group::Lookahead { .. } => Feature::Lookahead,
+            Group::Lookbehind { .. } => Feature::Lookbehind,
+            Group::Scoped(_) | Group::Flags(_) => Feature::InlineFlags,
+            Group::Reference(_) => Feature::NamedBackreference,
+        }
+    }
+}
+
+impl Display for Dialect {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Dialect::Pcre => "PCRE",
+            Dialect::PosixBasic => "POSIX basic regular expressions",
+            Dialect::PosixExtended => "POSIX extended regular expressions",
+            Dialect::JavaScript => "JavaScript",
+            Dialect::Python => "Python",
+            Dialect::Go => "Go",
+            Dialect::Rust => "Rust",
+        })
+    }
+}
+
+/// Accepts `pcre`, `bre`, `ere`, `js`, `python`, `go` or `rust`, along with a few longer names
+impl FromStr for Dialect {
+    type Err = ValueError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, ValueError> {
+        match s.to_ascii_lowercase().as_str() {
+            "pcre" | "perl" => Ok(Dialect::Pcre),
+            "bre" | "posix-basic" => Ok(Dialect::PosixBasic),
+            "ere" | "posix-extended" | "posix" => Ok(Dialect::PosixExtended),
+            "js" | "javascript" | "ecmascript" => Ok(Dialect::JavaScript),
+            "python" | "py" => Ok(Dialect::Python),
+            "go" | "re2" => Ok(Dialect::Go),
+            "rust" => Ok(Dialect::Rust),
+            _ => Err(ValueError::Unknown {
+                expected: "`pcre`, `bre`, `ere`, `js`, `python`, `go` or `rust`",
+                found: s.to_string(),
+            }),
+        }
+    }
+}
+
+impl Display for Feature {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Feature::NonCapturingGroup => "Non-capturing group",
+            Feature::NamedGroup => "Named group",
+            Feature::InlineFlags => "Flag group",
+            Feature::LazyQuantifier => "Lazy quantifier",
+            Feature::PossessiveQuantifier => "Possessive quantifier",
+            Feature::AtomicGroup => "Atomic group",
+            Feature::Lookahead => "Lookahead",
+            Feature::Lookbehind => "Lookbehind",
+            Feature::Backreference => "Backreference",
+            Feature::NamedBackreference => "Named backreference",
+            Feature::ShorthandClass => "Shorthand class",
+            Feature::UnicodeProperty => "Unicode property",
+            Feature::WordBoundary => "Word boundary",
+            Feature::StartOfText => "Start of text anchor",
+            Feature::EndOfText => "End of text anchor",
+            Feature::EndOfTextOrNewline => "End of text or final newline anchor",
+            Feature::InnerAnchor => "Anchor in the middle of a sequence",
+            Feature::PosixClass => "POSIX character class",
+            Feature::CollatingElement => "Collating element",
+        })
+    }
+}
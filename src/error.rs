@@ -0,0 +1,276 @@
+use std::fmt::Write;
+
+use thiserror::Error;
+
+use crate::dialect::{Dialect, Feature};
+use crate::syntax::Syntax;
+use crate::tokens::{EscapeError, TokenMeta};
+
+pub(crate) type Result<T> = std::result::Result<T, ParseError>;
+
+/// Where in the expression a problem is, counted in characters from the start, not including
+/// `end`
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub(crate) fn new(start: usize, end: usize) -> Self {
+        Span {
+            start,
+            end: end.max(start + 1),
+        }
+    }
+}
+
+impl From<TokenMeta> for Span {
+    fn from(meta: TokenMeta) -> Self {
+        Span::new(meta.position, meta.position + 1)
+    }
+}
+
+/// Why an expression couldn't be parsed
+#[derive(Debug, Clone, Eq, PartialEq, Error)]
+#[non_exhaustive]
+pub enum ParseError {
+    #[error("Malformed escape at position {}: {error}", span.start)]
+    MalformedEscape { error: EscapeError, span: Span },
+    #[error("Escape character at position {} has nothing to escape", span.start)]
+    TrailingEscape { span: Span },
+    #[error("Unexpected {token} at position {}", span.start)]
+    UnexpectedToken { token: String, span: Span },
+    #[error("Unclosed {bracket} at position {}", span.start)]
+    UnclosedBracket { bracket: char, span: Span },
+    #[error("Unmatched {bracket} at position {}", span.start)]
+    UnmatchedBracket { bracket: char, span: Span },
+    #[error("Mismatched brackets: {open} closed by {close} at position {}", span.start)]
+    MismatchedBrackets { open: char, close: char, span: Span },
+    #[error("Nothing to repeat before {quantifier} at position {}", span.start)]
+    NothingToRepeat { quantifier: char, span: Span },
+    #[error("Invalid repetition count {count} at position {}: {reason}", span.start)]
+    InvalidRepetitionCount {
+        count: String,
+        reason: String,
+        span: Span,
+    },
+    #[error("Repetition {{{text}}} at position {} has its bounds out of order", span.start)]
+    RepetitionOutOfOrder { text: String, span: Span },
+    #[error("Invalid range {range} at position {}", span.start)]
+    InvalidRange { range: String, span: Span },
+    #[error("Unclosed Unicode property name at position {}", span.start)]
+    UnclosedProperty { span: Span },
+    #[error("Unknown Unicode property {name:?} at position {}", span.start)]
+    UnknownProperty { name: String, span: Span },
+    #[error("Unknown character class [:{name}:] at position {}", span.start)]
+    UnknownClass { name: String, span: Span },
+    #[error("Unknown collating element {name:?} at position {}", span.start)]
+    UnknownCollatingElement { name: String, span: Span },
+    #[error("Unknown group syntax (?{syntax} at position {}", span.start)]
+    UnknownGroup { syntax: String, span: Span },
+    #[error("Group at position {} has an empty name", span.start)]
+    EmptyGroupName { span: Span },
+    #[error("Invalid group name {name:?} at position {}", span.start)]
+    InvalidGroupName { name: String, span: Span },
+    #[error("Unknown flag {flag:?} at position {}", span.start)]
+    UnknownFlag { flag: char, span: Span },
+    #[error("Group at position {} has no flags", span.start)]
+    NoFlags { span: Span },
+    #[error("There's more than one group named {name:?}, the second at position {}", span.start)]
+    DuplicateGroupName { name: String, span: Span },
+    #[error("There's no group {reference} before the backreference at position {}", span.start)]
+    MissingGroup { reference: String, span: Span },
+    #[error("{feature} at position {} isn't supported in {dialect}", span.start)]
+    Unsupported {
+        feature: Feature,
+        dialect: Dialect,
+        span: Span,
+    },
+    #[error("Expression is nested more than {limit} deep at position {}", span.start)]
+    NestedTooDeep { limit: usize, span: Span },
+    /// Something that should never happen, whatever the expression
+    #[error("Internal error at position {}: {message}", span.start)]
+    Internal { message: String, span: Span },
+}
+
+/// Why a setting given as text, like the name of a dialect, couldn't be understood
+#[derive(Debug, Clone, Eq, PartialEq, Error)]
+#[non_exhaustive]
+pub enum ValueError {
+    #[error("Expected {expected}, but found {found}")]
+    Unknown {
+        expected: &'static str,
+        found: String,
+    },
+    #[error("A {syntax} has no escape character to set")]
+    NoEscape { syntax: Syntax },
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+}
+
+/// The kind of a [`ParseError`], without the details
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    MalformedEscape,
+    TrailingEscape,
+    UnexpectedToken,
+    UnclosedBracket,
+    UnmatchedBracket,
+    MismatchedBrackets,
+    NothingToRepeat,
+    InvalidRepetitionCount,
+    RepetitionOutOfOrder,
+    InvalidRange,
+    UnclosedProperty,
+    UnknownProperty,
+    UnknownClass,
+    UnknownCollatingElement,
+    UnknownGroup,
+    EmptyGroupName,
+    InvalidGroupName,
+    UnknownFlag,
+    NoFlags,
+    DuplicateGroupName,
+    MissingGroup,
+    Unsupported,
+    NestedTooDeep,
+    Internal,
+}
+
+impl ParseError {
+    /// The part of the expression that the error is about
+    pub fn span(&self) -> Span {
+        match self {
+            ParseError::MalformedEscape { span, .. }
+            | ParseError::TrailingEscape { span }
+            | ParseError::UnexpectedToken { span, .. }
+            | ParseError::UnclosedBracket { span, .. }
+            | ParseError::UnmatchedBracket { span, .. }
+            | ParseError::MismatchedBrackets { span, .. }
+            | ParseError::NothingToRepeat { span, .. }
+            | ParseError::InvalidRepetitionCount { span, .. }
+            | ParseError::RepetitionOutOfOrder { span, .. }
+            | ParseError::InvalidRange { span, .. }
+            | ParseError::UnclosedProperty { span }
+            | ParseError::UnknownProperty { span, .. }
+            | ParseError::UnknownClass { span, .. }
+            | ParseError::UnknownCollatingElement { span, .. }
+            | ParseError::UnknownGroup { span, .. }
+            | ParseError::EmptyGroupName { span }
+            | ParseError::InvalidGroupName { span, .. }
+            | ParseError::UnknownFlag { span, .. }
+            | ParseError::NoFlags { span }
+            | ParseError::DuplicateGroupName { span, .. }
+            | ParseError::MissingGroup { span, .. }
+            | ParseError::Unsupported { span, .. }
+            | ParseError::NestedTooDeep { span, .. }
+            | ParseError::Internal { span, .. } => *span,
+        }
+    }
+
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            ParseError::MalformedEscape { .. } => ErrorKind::MalformedEscape,
+            ParseError::TrailingEscape { .. } => ErrorKind::TrailingEscape,
+            ParseError::UnexpectedToken { .. } => ErrorKind::UnexpectedToken,
+            ParseError::UnclosedBracket { .. } => ErrorKind::UnclosedBracket,
+            ParseError::UnmatchedBracket { .. } => ErrorKind::UnmatchedBracket,
+            ParseError::MismatchedBrackets { .. } => ErrorKind::MismatchedBrackets,
+            ParseError::NothingToRepeat { .. } => ErrorKind::NothingToRepeat,
+            ParseError::InvalidRepetitionCount { .. } => ErrorKind::InvalidRepetitionCount,
+            ParseError::RepetitionOutOfOrder { .. } => ErrorKind::RepetitionOutOfOrder,
+            ParseError::InvalidRange { .. } => ErrorKind::InvalidRange,
+            ParseError::UnclosedProperty { .. } => ErrorKind::UnclosedProperty,
+            ParseError::UnknownProperty { .. } => ErrorKind::UnknownProperty,
+            ParseError::UnknownClass { .. } => ErrorKind::UnknownClass,
+            ParseError::UnknownCollatingElement { .. } => ErrorKind::UnknownCollatingElement,
+            ParseError::UnknownGroup { .. } => ErrorKind::UnknownGroup,
+            ParseError::EmptyGroupName { .. } => ErrorKind::EmptyGroupName,
+            ParseError::InvalidGroupName { .. } => ErrorKind::InvalidGroupName,
+            ParseError::UnknownFlag { .. } => ErrorKind::UnknownFlag,
+            ParseError::NoFlags { .. } => ErrorKind::NoFlags,
+            ParseError::DuplicateGroupName { .. } => ErrorKind::DuplicateGroupName,
+            ParseError::MissingGroup { .. } => ErrorKind::MissingGroup,
+            ParseError::Unsupported { .. } => ErrorKind::Unsupported,
+            ParseError::NestedTooDeep { .. } => ErrorKind::NestedTooDeep,
+            ParseError::Internal { .. } => ErrorKind::Internal,
+        }
+    }
+
+    pub(crate) fn internal(message: impl Into<String>) -> Self {
+        ParseError::Internal {
+            message: message.into(),
+            span: Span::default(),
+        }
+    }
+
+    /// Describes the error like a compiler would, quoting the line of the expression it's on and
+    /// pointing to the problem with carets
+    pub fn render(&self, expression: &str) -> String {
+        let span = self.span();
+        let mut line_start = 0;
+        let mut lines = expression.split('\n').enumerate().peekable();
+        let (number, line) = loop {
+            match lines.next() {
+                Some((number, line)) => {
+                    let length = line.chars().count();
+                    if span.start <= line_start + length || lines.peek().is_none() {
+                        break (number + 1, line);
+                    }
+                    line_start += length + 1;
+                }
+                None => break (1, ""),
+            }
+        };
+        let column = span.start.saturating_sub(line_start);
+        let length = line.chars().count();
+        let carets = span
+            .end
+            .min(line_start + length)
+            .saturating_sub(span.start)
+            .max(1);
+
+        let gutter = " ".repeat(number.to_string().len());
+        let mut rendered = String::new();
+        let _ = writeln!(rendered, "error[{:?}]: {self}", self.kind());
+        let _ = writeln!(rendered, "{gutter} |");
+        let _ = writeln!(rendered, "{number} | {line}");
+        let _ = write!(
+            rendered,
+            "{gutter} | {}{}",
+            " ".repeat(column),
+            "^".repeat(carets)
+        );
+        rendered
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::error::{ParseError, Span};
+
+    #[rstest]
+    #[case("a(?Q)", ParseError::UnknownGroup { syntax: "Q".to_string(), span: Span::new(1, 4) }, "\
+error[UnknownGroup]: Unknown group syntax (?Q at position 1
+  |
+1 | a(?Q)
+  |  ^^^")]
+    #[case("(a", ParseError::UnclosedBracket { bracket: '(', span: Span::new(0, 1) }, "\
+error[UnclosedBracket]: Unclosed ( at position 0
+  |
+1 | (a
+  | ^")]
+    #[case("a\n# b\nc\\", ParseError::TrailingEscape { span: Span::new(7, 8) }, "\
+error[TrailingEscape]: Escape character at position 7 has nothing to escape
+  |
+3 | c\\
+  |  ^")]
+    fn test_render(#[case] expression: &str, #[case] error: ParseError, #[case] expected: &str) {
+        assert_eq!(expected, error.render(expression));
+    }
+}
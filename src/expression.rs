@@ -1,66 +1,1051 @@
+use std::cell::{Cell, RefCell};
+use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::fmt::Debug;
+use std::iter::once;
+use std::ops::Deref;
+use std::rc::Rc;
 
 use itertools::Itertools;
 
-trait ClonableStringIterator: Iterator + Clone {}
+use crate::expression::matcher::Matcher;
+use crate::unicode::PERL_WORD;
 
-pub(crate) trait Expression: Debug {
-    fn example(&self) -> String;
-    fn enumerate(&self) -> Box<dyn Iterator<Item = String> + '_>;
+mod matcher;
+
+/// Picks out an expression in an [`Arena`].
+///
+/// The arena stores each expression once, so two ids are equal exactly when their expressions
+/// are, and comparing or hashing them doesn't depend on how big the expressions are.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub(crate) struct Id(usize);
+
+/// One node of an expression, which refers to the expressions inside it by id
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+enum Expression {
+    Literal(String),
+    /// Any one character from the set
+    Set(CharacterSet),
+    Anchor(Anchor),
+    /// A zero-width assertion that the text ahead of or behind this point does (or doesn't)
+    /// match
+    Lookaround {
+        direction: Direction,
+        negated: bool,
+        expression: Id,
+    },
+    /// The text matched by `text` that also starts (looking ahead) or ends (looking behind) with
+    /// a match of `other`.
+    ///
+    /// This is how `(?=a)b` is lowered to the intersection of `a.*` and `b`, and `b(?<=a)` to the
+    /// intersection of `b` and `.*a`.
+    Intersection {
+        text: Id,
+        other: Id,
+        direction: Direction,
+    },
+    /// The text matched by `text` that doesn't start (looking ahead) or end (looking behind) with
+    /// a match of `other`, which is how `(?!a)b` and `b(?<!a)` are lowered
+    Difference {
+        text: Id,
+        other: Id,
+        direction: Direction,
+    },
+    Alternate(Vec<Id>),
+    Optional(Id),
+    Star(Id),
+    Plus(Id),
+    /// Repeated at least `min` times, and at most `max` times if there's an upper bound
+    Repeat(Id, u32, Option<u32>),
+    /// Like `Repeat`, but trying as few repetitions as possible first
+    Lazy(Id, u32, Option<u32>),
+    /// Like `Repeat`, but never giving back repetitions once they have matched
+    Possessive(Id, u32, Option<u32>),
+    /// Matches like its content, but only ever the first way it finds
+    Atomic(Id),
+    Sequence(Vec<Id>),
+    /// A capturing group, numbered by the position of its opening parenthesis
+    Capture {
+        index: usize,
+        expression: Id,
+    },
+    /// Matches the text that group `index` last captured again.
+    ///
+    /// Generation can't see what the group captured, so it uses `expression`, which generates
+    /// what the group could capture, and relies on filtering to keep the candidates that repeat
+    /// it. It's `None` for a reference inside its own group, which never matches the first time
+    /// round.
+    Backreference {
+        index: usize,
+        case_insensitive: bool,
+        expression: Option<Id>,
+    },
 }
 
-#[derive(Debug)]
-pub(crate) struct Literal(pub(crate) String);
+/// What's known about the text an expression matches, without matching anything: how long it is
+/// at least, and which characters its matches can start and end with when they aren't empty.
+///
+/// The sets can have characters in them that no match starts or ends with, but never leave any
+/// out, so they can only show that something can't match.
+#[derive(Debug, Clone)]
+struct Summary {
+    min: usize,
+    first: CharacterSet,
+    last: CharacterSet,
+}
+
+/// Stores expressions, hash-consed so that each distinct one is only stored once.
+///
+/// Expressions can only be built with the constructors here, which put them in a normal form as
+/// they go: nested sequences and alternations are flattened, adjacent literals are joined,
+/// repeated alternatives are dropped, sequences with assertions in them that can't hold match
+/// nothing, and so on. Expressions written differently but built the same way, like `a(?:bc)` and
+/// `abc`, end up with the same id. None of this changes which strings match.
+#[derive(Debug, Default)]
+pub(crate) struct Arena {
+    expressions: Vec<Expression>,
+    summaries: Vec<Summary>,
+    ids: HashMap<Expression, Id>,
+}
+
+impl Arena {
+    /// The id of the expression, storing it if it isn't already stored
+    fn intern(&mut self, expression: Expression) -> Id {
+        if let Some(id) = self.ids.get(&expression) {
+            return *id;
+        }
+        let id = Id(self.expressions.len());
+        self.summaries.push(self.summarise(&expression));
+        self.expressions.push(expression.clone());
+        self.ids.insert(expression, id);
+        id
+    }
+
+    fn get(&self, id: Id) -> &Expression {
+        &self.expressions[id.0]
+    }
+
+    fn summary(&self, id: Id) -> &Summary {
+        &self.summaries[id.0]
+    }
+
+    fn summarise(&self, expression: &Expression) -> Summary {
+        let none = || CharacterSet::new([]);
+        match expression {
+            Expression::Literal(text) => {
+                let set = |c: Option<char>| CharacterSet::new(c.map(|c| (c, c)));
+                Summary {
+                    min: text.chars().count(),
+                    first: set(text.chars().next()),
+                    last: set(text.chars().last()),
+                }
+            }
+            Expression::Set(set) => Summary {
+                min: 1,
+                first: set.clone(),
+                last: set.clone(),
+            },
+            Expression::Anchor(_) | Expression::Lookaround { .. } => Summary {
+                min: 0,
+                first: none(),
+                last: none(),
+            },
+            Expression::Intersection { text, .. }
+            | Expression::Difference { text, .. }
+            | Expression::Plus(text)
+            | Expression::Atomic(text)
+            | Expression::Capture {
+                expression: text, ..
+            } => self.summary(*text).clone(),
+            Expression::Optional(e) | Expression::Star(e) => Summary {
+                min: 0,
+                ..self.summary(*e).clone()
+            },
+            Expression::Repeat(e, min, _)
+            | Expression::Lazy(e, min, _)
+            | Expression::Possessive(e, min, _) => {
+                let summary = self.summary(*e);
+                Summary {
+                    min: summary.min.saturating_mul(*min as usize),
+                    ..summary.clone()
+                }
+            }
+            Expression::Alternate(v) => Summary {
+                min: v.iter().map(|e| self.summary(*e).min).min().unwrap_or(0),
+                first: v
+                    .iter()
+                    .fold(none(), |set, e| set.union(&self.summary(*e).first)),
+                last: v
+                    .iter()
+                    .fold(none(), |set, e| set.union(&self.summary(*e).last)),
+            },
+            Expression::Sequence(v) => {
+                let (min, first) = self.edge(v.iter(), |s| &s.first);
+                let (_, last) = self.edge(v.iter().rev(), |s| &s.last);
+                Summary { min, first, last }
+            }
+            // What the group captured could be anything, as far as we know here
+            Expression::Backreference { .. } => Summary {
+                min: 0,
+                first: CharacterSet::new([('\0', char::MAX)]),
+                last: CharacterSet::new([('\0', char::MAX)]),
+            },
+        }
+    }
+
+    /// The least length of the expressions one after another, and the characters the first of
+    /// them to match something non-empty can have at its edge (as picked by `side`)
+    fn edge<'a>(
+        &self,
+        parts: impl Iterator<Item = &'a Id>,
+        side: fn(&Summary) -> &CharacterSet,
+    ) -> (usize, CharacterSet) {
+        let (mut min, mut set) = (0_usize, CharacterSet::new([]));
+        for id in parts {
+            let summary = self.summary(*id);
+            if min == 0 {
+                set = set.union(side(summary));
+            }
+            min = min.saturating_add(summary.min);
+        }
+        (min, set)
+    }
+
+    /// Whether each anchor and lookaround in the sequence could hold, given what comes before and
+    /// after it in the sequence
+    fn assertions_can_hold(&self, parts: &[Id]) -> bool {
+        parts.iter().enumerate().all(|(i, id)| {
+            let (before, last) = self.edge(parts[..i].iter().rev(), |s| &s.last);
+            let (after, first) = self.edge(parts[i + 1..].iter(), |s| &s.first);
+            let anchor = match self.get(*id) {
+                Expression::Anchor(anchor) => *anchor,
+                Expression::Lookaround {
+                    direction,
+                    negated: false,
+                    expression,
+                } => {
+                    let summary = self.summary(*expression);
+                    return match direction {
+                        Direction::Ahead if summary.min > 0 && after > 0 => {
+                            !summary.first.intersection(&first).is_empty()
+                        }
+                        Direction::Behind if summary.min > 0 && before > 0 => {
+                            !summary.last.intersection(&last).is_empty()
+                        }
+                        _ => true,
+                    };
+                }
+                _ => return true,
+            };
+            match anchor {
+                Anchor::StartOfText => before == 0,
+                Anchor::EndOfText => after == 0,
+                Anchor::EndOfTextOrNewline => after == 0 || after == 1 && first.contains('\n'),
+                Anchor::StartOfLine => before == 0 || last.contains('\n'),
+                Anchor::EndOfLine => after == 0 || first.contains('\n'),
+                Anchor::WordBoundary { negated, unicode } if before > 0 && after > 0 => {
+                    let word = match unicode {
+                        true => CharacterSet::new(PERL_WORD.iter().copied()),
+                        false => {
+                            CharacterSet::new([('0', '9'), ('A', 'Z'), ('_', '_'), ('a', 'z')])
+                        }
+                    };
+                    // Whether every character in the set is (or isn't) a word character
+                    let all = |set: &CharacterSet, is_word: bool| match is_word {
+                        true => set.difference(&word).is_empty(),
+                        false => set.intersection(&word).is_empty(),
+                    };
+                    [true, false].iter().all(|&is_word| {
+                        let other = is_word != negated;
+                        !(all(&last, is_word) && all(&first, other))
+                    })
+                }
+                Anchor::WordBoundary { .. } => true,
+            }
+        })
+    }
+
+    /// Matches the empty string
+    pub(crate) fn empty(&mut self) -> Id {
+        self.literal(String::new())
+    }
+
+    /// Matches nothing at all
+    pub(crate) fn nothing(&mut self) -> Id {
+        self.set(CharacterSet::new([]))
+    }
+
+    fn is_empty(&self, id: Id) -> bool {
+        matches!(self.get(id), Expression::Literal(text) if text.is_empty())
+    }
+
+    fn is_nothing(&self, id: Id) -> bool {
+        matches!(self.get(id), Expression::Set(set) if set.is_empty())
+    }
+
+    pub(crate) fn literal(&mut self, text: String) -> Id {
+        self.intern(Expression::Literal(text))
+    }
+
+    pub(crate) fn set(&mut self, set: CharacterSet) -> Id {
+        self.intern(Expression::Set(set))
+    }
+
+    pub(crate) fn anchor(&mut self, anchor: Anchor) -> Id {
+        self.intern(Expression::Anchor(anchor))
+    }
+
+    /// The assertion, or the empty string or nothing if it always or never holds
+    pub(crate) fn lookaround(&mut self, direction: Direction, negated: bool, expression: Id) -> Id {
+        if self.is_nothing(expression) || self.is_empty(expression) {
+            return match self.is_empty(expression) != negated {
+                true => self.empty(),
+                false => self.nothing(),
+            };
+        }
+        self.intern(Expression::Lookaround {
+            direction,
+            negated,
+            expression,
+        })
+    }
+
+    /// The intersection, or nothing if the text can't start (or end) with a match of `other`
+    pub(crate) fn intersection(&mut self, text: Id, other: Id, direction: Direction) -> Id {
+        if self.is_empty(other) {
+            return text;
+        }
+        let (text_summary, other_summary) = (self.summary(text), self.summary(other));
+        let edges = match direction {
+            Direction::Ahead => (&text_summary.first, &other_summary.first),
+            Direction::Behind => (&text_summary.last, &other_summary.last),
+        };
+        let disjoint = text_summary.min > 0
+            && other_summary.min > 0
+            && edges.0.intersection(edges.1).is_empty();
+        if self.is_nothing(text) || self.is_nothing(other) || disjoint {
+            return self.nothing();
+        }
+        self.intern(Expression::Intersection {
+            text,
+            other,
+            direction,
+        })
+    }
+
+    pub(crate) fn difference(&mut self, text: Id, other: Id, direction: Direction) -> Id {
+        if self.is_nothing(text) || self.is_nothing(other) {
+            return text;
+        }
+        if self.is_empty(other) {
+            return self.nothing();
+        }
+        self.intern(Expression::Difference {
+            text,
+            other,
+            direction,
+        })
+    }
+
+    /// The alternatives, with any nested alternations flattened into them, and those that match
+    /// nothing or repeat an earlier one left out
+    pub(crate) fn alternate(&mut self, alternatives: Vec<Id>) -> Id {
+        let mut kept: Vec<Id> = vec![];
+        for id in alternatives {
+            let inner = match self.get(id) {
+                Expression::Alternate(inner) => inner.clone(),
+                _ => vec![id],
+            };
+            for id in inner {
+                if !self.is_nothing(id) && !kept.contains(&id) {
+                    kept.push(id);
+                }
+            }
+        }
+        match kept[..] {
+            [] => self.nothing(),
+            [id] => id,
+            _ => self.intern(Expression::Alternate(kept)),
+        }
+    }
+
+    /// The expressions one after another, with any nested sequences flattened into them, empty
+    /// literals left out and adjacent literals joined up. If any of them matches nothing, or is an
+    /// assertion that can't hold where it is, the sequence matches nothing.
+    pub(crate) fn sequence(&mut self, expressions: Vec<Id>) -> Id {
+        let mut parts: Vec<Id> = vec![];
+        for id in expressions {
+            let inner = match self.get(id) {
+                Expression::Sequence(inner) => inner.clone(),
+                _ => vec![id],
+            };
+            for id in inner {
+                if self.is_nothing(id) {
+                    return id;
+                }
+                let joined = match (parts.last().map(|last| self.get(*last)), self.get(id)) {
+                    (_, Expression::Literal(text)) if text.is_empty() => continue,
+                    (Some(Expression::Literal(before)), Expression::Literal(text)) => {
+                        format!("{before}{text}")
+                    }
+                    _ => {
+                        parts.push(id);
+                        continue;
+                    }
+                };
+                parts.pop();
+                let joined = self.literal(joined);
+                parts.push(joined);
+            }
+        }
+        match parts[..] {
+            [] => self.empty(),
+            [id] => id,
+            _ if !self.assertions_can_hold(&parts) => self.nothing(),
+            _ => self.intern(Expression::Sequence(parts)),
+        }
+    }
+
+    pub(crate) fn optional(&mut self, expression: Id) -> Id {
+        match self.get(expression) {
+            Expression::Optional(_) | Expression::Star(_) => expression,
+            _ if self.is_empty(expression) || self.is_nothing(expression) => self.empty(),
+            _ => self.intern(Expression::Optional(expression)),
+        }
+    }
+
+    pub(crate) fn star(&mut self, expression: Id) -> Id {
+        match self.get(expression) {
+            Expression::Star(_) => expression,
+            _ if self.is_empty(expression) || self.is_nothing(expression) => self.empty(),
+            _ => self.intern(Expression::Star(expression)),
+        }
+    }
+
+    pub(crate) fn plus(&mut self, expression: Id) -> Id {
+        match self.get(expression) {
+            Expression::Plus(_) | Expression::Star(_) => expression,
+            _ if self.is_empty(expression) || self.is_nothing(expression) => expression,
+            _ => self.intern(Expression::Plus(expression)),
+        }
+    }
+
+    pub(crate) fn repeat(&mut self, expression: Id, min: u32, max: Option<u32>) -> Id {
+        match (min, max) {
+            (_, Some(0)) => self.empty(),
+            (1, Some(1)) => expression,
+            // Generates the same strings in the same order
+            (0, None) => self.star(expression),
+            _ => self.repetition(expression, min, max, Expression::Repeat),
+        }
+    }
+
+    pub(crate) fn lazy(&mut self, expression: Id, min: u32, max: Option<u32>) -> Id {
+        match (min, max) {
+            (_, Some(0)) => self.empty(),
+            (1, Some(1)) => expression,
+            _ => self.repetition(expression, min, max, Expression::Lazy),
+        }
+    }
+
+    pub(crate) fn possessive(&mut self, expression: Id, min: u32, max: Option<u32>) -> Id {
+        match (min, max) {
+            (_, Some(0)) => self.empty(),
+            (1, Some(1)) => self.atomic(expression),
+            _ => self.repetition(expression, min, max, Expression::Possessive),
+        }
+    }
+
+    /// A bounded or counted repetition, which matches the empty string if its content does, and
+    /// nothing if its content matches nothing and it needs at least one
+    fn repetition(
+        &mut self,
+        expression: Id,
+        min: u32,
+        max: Option<u32>,
+        kind: fn(Id, u32, Option<u32>) -> Expression,
+    ) -> Id {
+        if self.is_empty(expression) || self.is_nothing(expression) && min == 0 {
+            self.empty()
+        } else if self.is_nothing(expression) {
+            expression
+        } else {
+            self.intern(kind(expression, min, max))
+        }
+    }
+
+    /// Matches like `expression`, but only the first way it finds. Expressions that can only
+    /// match one way are left as they are.
+    pub(crate) fn atomic(&mut self, expression: Id) -> Id {
+        match self.get(expression) {
+            Expression::Literal(_)
+            | Expression::Set(_)
+            | Expression::Anchor(_)
+            | Expression::Lookaround { .. }
+            | Expression::Backreference { .. }
+            | Expression::Atomic(_)
+            | Expression::Possessive(..) => expression,
+            _ => self.intern(Expression::Atomic(expression)),
+        }
+    }
+
+    pub(crate) fn capture(&mut self, index: usize, expression: Id) -> Id {
+        self.intern(Expression::Capture { index, expression })
+    }
+
+    pub(crate) fn backreference(
+        &mut self,
+        index: usize,
+        case_insensitive: bool,
+        expression: Option<Id>,
+    ) -> Id {
+        self.intern(Expression::Backreference {
+            index,
+            case_insensitive,
+            expression,
+        })
+    }
+
+    pub(crate) fn is_match(&self, id: Id, text: &str) -> bool {
+        let haystack = Haystack::new(text);
+        self.match_at(id, &haystack, 0, &|end| end == haystack.len())
+    }
+
+    /// An example from the language, if the language isn't empty and one turns up within
+    /// [`EXAMPLE_STEPS`] steps of generating candidates
+    pub(crate) fn example(&self, id: Id) -> Option<String> {
+        let candidate = self.candidate(id);
+        if self.is_match(id, &candidate) {
+            Some(candidate)
+        } else {
+            self.matching(id, Budget::new(EXAMPLE_STEPS)).next()
+        }
+    }
+
+    /// The generated strings that actually match, once assertions are taken into account
+    pub(crate) fn enumerate(&self, id: Id) -> Box<dyn Iterator<Item = String> + '_> {
+        self.matching(id, Budget::new(usize::MAX))
+    }
+
+    /// The generated strings that match, for as long as the budget lasts
+    fn matching(&self, id: Id, budget: Budget) -> Box<dyn Iterator<Item = String> + '_> {
+        Box::new(
+            self.candidates(id, &budget)
+                .take_while(move |_| budget.spend())
+                .filter(move |c| self.is_match(id, c)),
+        )
+    }
 
-impl Expression for Literal {
-    fn example(&self) -> String {
-        self.0.to_string()
+    /// A representative string generated by the expression, ignoring any assertions
+    fn candidate(&self, id: Id) -> String {
+        match self.get(id) {
+            Expression::Literal(text) => text.clone(),
+            Expression::Set(set) => set.candidate(),
+            Expression::Anchor(_) | Expression::Lookaround { .. } => String::new(),
+            Expression::Intersection {
+                text,
+                other,
+                direction: Direction::Ahead,
+            } => self.candidate(*other) + &self.candidate(*text),
+            Expression::Intersection {
+                text,
+                other,
+                direction: Direction::Behind,
+            } => self.candidate(*text) + &self.candidate(*other),
+            Expression::Difference { text, .. } => self.candidate(*text),
+            Expression::Alternate(v) => self.candidate(v[0]),
+            Expression::Optional(e)
+            | Expression::Star(e)
+            | Expression::Plus(e)
+            | Expression::Atomic(e)
+            | Expression::Capture { expression: e, .. } => self.candidate(*e),
+            Expression::Repeat(e, min, max)
+            | Expression::Lazy(e, min, max)
+            | Expression::Possessive(e, min, max) => {
+                let count = if *min == 0 && *max != Some(0) {
+                    1
+                } else {
+                    *min
+                };
+                self.candidate(*e).repeat(count as usize)
+            }
+            Expression::Sequence(v) => v.iter().map(|e| self.candidate(*e)).collect(),
+            Expression::Backreference { expression, .. } => {
+                expression.map(|e| self.candidate(e)).unwrap_or_default()
+            }
+        }
+    }
+
+    /// The strings generated by the expression, ignoring any assertions, which stop once the
+    /// budget runs out
+    fn candidates(&self, id: Id, budget: &Budget) -> Box<dyn Iterator<Item = String> + '_> {
+        match self.get(id) {
+            Expression::Literal(_) | Expression::Anchor(_) | Expression::Lookaround { .. } => {
+                Box::new(once(self.candidate(id)))
+            }
+            Expression::Set(set) => Box::new(set.sample().map(String::from)),
+            Expression::Intersection {
+                text,
+                other,
+                direction,
+            } => self.intersection_candidates(id, *text, *other, *direction, budget),
+            Expression::Difference { text, .. } => {
+                let spent = budget.clone();
+                Box::new(
+                    self.candidates(*text, budget)
+                        .take_while(move |_| spent.spend())
+                        .filter(move |c| self.is_match(id, c)),
+                )
+            }
+            Expression::Alternate(v) => {
+                let budget = budget.clone();
+                Box::new(v.iter().flat_map(move |e| self.candidates(*e, &budget)))
+            }
+            Expression::Optional(e) => Box::new(self.candidates(*e, budget).chain([String::new()])),
+            Expression::Star(e) => Box::new(
+                [String::new()]
+                    .into_iter()
+                    .chain(self.candidates(*e, budget))
+                    .chain(self.product(self.candidates(*e, budget), *e, budget)),
+            ),
+            Expression::Plus(e) => Box::new(self.candidates(*e, budget).chain(self.product(
+                self.candidates(*e, budget),
+                *e,
+                budget,
+            ))),
+            Expression::Repeat(e, min, max)
+            | Expression::Lazy(e, min, max)
+            | Expression::Possessive(e, min, max) => {
+                // Like Star, we only go a couple of repetitions past the minimum if there's no limit
+                let max = max.unwrap_or(min.saturating_add(2));
+                let budget = budget.clone();
+                Box::new((*min..=max).flat_map(move |count| {
+                    (0..count).fold(
+                        Box::new(once(String::new())) as Box<dyn Iterator<Item = String> + '_>,
+                        |prev, _| self.product(prev, *e, &budget),
+                    )
+                }))
+            }
+            Expression::Atomic(e) | Expression::Capture { expression: e, .. } => {
+                self.candidates(*e, budget)
+            }
+            Expression::Sequence(v) => v.iter().fold(
+                Box::new(once(String::new())),
+                |prev: Box<dyn Iterator<Item = String>>, e| self.product(prev, *e, budget),
+            ),
+            Expression::Backreference { expression, .. } => match expression {
+                Some(e) => self.candidates(*e, budget),
+                None => Box::new(std::iter::empty()),
+            },
+        }
+    }
+
+    /// Each candidate for `text`, followed by it with each candidate for `other` written over its
+    /// start (or end) and then attached to it, since an arbitrary `.*` would rarely produce text
+    /// that `text` matches.
+    ///
+    /// Only the candidates that match are kept, once each, so that intersections can be stacked
+    /// up without the number of candidates to check growing with each one.
+    fn intersection_candidates(
+        &self,
+        id: Id,
+        text: Id,
+        other: Id,
+        direction: Direction,
+        budget: &Budget,
+    ) -> Box<dyn Iterator<Item = String> + '_> {
+        let (inner, spent) = (budget.clone(), budget.clone());
+        Box::new(
+            self.candidates(text, budget)
+                .flat_map(move |text| {
+                    let combined = self.candidates(other, &inner).flat_map({
+                        let text: Vec<char> = text.chars().collect();
+                        move |other| {
+                            let length = other.chars().count();
+                            let rest = &text[length.min(text.len())..];
+                            let kept = &text[..text.len().saturating_sub(length)];
+                            match direction {
+                                Direction::Ahead => [
+                                    other.chars().chain(rest.iter().copied()).collect(),
+                                    other.chars().chain(text.iter().copied()).collect(),
+                                ],
+                                Direction::Behind => [
+                                    kept.iter().copied().chain(other.chars()).collect(),
+                                    text.iter().copied().chain(other.chars()).collect(),
+                                ],
+                            }
+                        }
+                    });
+                    [text].into_iter().chain(combined)
+                })
+                .take_while(move |_| spent.spend())
+                .filter(move |c| self.is_match(id, c))
+                .unique(),
+        )
     }
 
-    fn enumerate(&self) -> Box<dyn Iterator<Item = String>> {
-        Box::new(vec![self.example()].into_iter())
+    /// Whether the expression matches from `position` to an end that `accept` allows
+    fn match_at(
+        &self,
+        id: Id,
+        haystack: &Haystack,
+        position: usize,
+        accept: &dyn Fn(usize) -> bool,
+    ) -> bool {
+        Matcher::new(self, haystack).run(id, position, accept)
+    }
+
+    /// Each prefix followed by each candidate for `e`, generating the candidates afresh for each
+    /// prefix rather than holding them all in memory. Each prefix costs a step, so that a long run
+    /// of prefixes that `e` adds nothing to still uses up the budget.
+    fn product<'a>(
+        &'a self,
+        prefixes: Box<dyn Iterator<Item = String> + 'a>,
+        e: Id,
+        budget: &Budget,
+    ) -> Box<dyn Iterator<Item = String> + 'a> {
+        let (inner, spent) = (budget.clone(), budget.clone());
+        Box::new(
+            prefixes
+                .take_while(move |_| spent.spend())
+                .flat_map(move |a| self.candidates(e, &inner).map(move |b| format!("{a}{b}"))),
+        )
     }
 }
 
+/// How many steps [`Arena::example`] takes generating candidates before it gives up
+const EXAMPLE_STEPS: usize = 100_000;
+
+/// How many more steps generating candidates can take, shared by all the iterators doing it so
+/// that a search that finds nothing still stops
+#[derive(Debug, Clone)]
+struct Budget(Rc<Cell<usize>>);
+
+impl Budget {
+    fn new(steps: usize) -> Self {
+        Budget(Rc::new(Cell::new(steps)))
+    }
+
+    /// Uses up a step, returning `false` if there were none left
+    fn spend(&self) -> bool {
+        let left = self.0.get();
+        self.0.set(left.saturating_sub(1));
+        left > 0
+    }
+}
+
+/// The text being matched, along with what each capture group has captured so far
 #[derive(Debug)]
-#[allow(dead_code)]
-pub(crate) enum ControlFlow {
-    Alternate(Vec<Box<dyn Expression>>),
-    Optional(Box<dyn Expression>),
-    Star(Box<dyn Expression>),
-    Sequence(Vec<Box<dyn Expression>>),
+pub(crate) struct Haystack {
+    chars: Vec<char>,
+    captures: RefCell<HashMap<usize, (usize, usize)>>,
+}
+
+impl Haystack {
+    fn new(text: &str) -> Self {
+        Haystack {
+            chars: text.chars().collect(),
+            captures: RefCell::default(),
+        }
+    }
+
+    /// Records the span that group `index` captured, returning what it had captured before
+    fn capture(&self, index: usize, span: Option<(usize, usize)>) -> Option<(usize, usize)> {
+        let mut captures = self.captures.borrow_mut();
+        match span {
+            Some(span) => captures.insert(index, span),
+            None => captures.remove(&index),
+        }
+    }
+
+    /// The text that group `index` last captured, if it has captured anything
+    fn captured(&self, index: usize) -> Option<&[char]> {
+        let (start, end) = *self.captures.borrow().get(&index)?;
+        Some(&self.chars[start..end])
+    }
+}
+
+impl Deref for Haystack {
+    type Target = [char];
+
+    fn deref(&self) -> &[char] {
+        &self.chars
+    }
+}
+
+/// A set of characters, any one of which matches
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub(crate) struct CharacterSet(Vec<(char, char)>);
+
+impl CharacterSet {
+    /// Sets larger than this are sampled, rather than enumerated in full
+    const SAMPLE_SIZE: u32 = 256;
+
+    /// Builds a set from inclusive ranges, which may overlap or be out of order
+    pub(crate) fn new<I: IntoIterator<Item = (char, char)>>(ranges: I) -> Self {
+        let mut ranges: Vec<_> = ranges.into_iter().collect();
+        ranges.sort();
+        let mut merged: Vec<(char, char)> = vec![];
+        for (start, end) in ranges {
+            match merged.last_mut() {
+                Some((_, last)) if Some(start) <= next_char(*last) => {
+                    *last = (*last).max(end);
+                }
+                _ => merged.push((start, end)),
+            }
+        }
+        CharacterSet(merged)
+    }
+
+    /// The characters in either set
+    pub(crate) fn union(&self, other: &CharacterSet) -> Self {
+        CharacterSet::new(self.0.iter().chain(&other.0).copied())
+    }
+
+    /// The characters in this set that are not in the other set
+    pub(crate) fn difference(&self, other: &CharacterSet) -> Self {
+        let mut ranges = vec![];
+        for &(start, end) in &self.0 {
+            let mut start = Some(start);
+            for &(remove_start, remove_end) in &other.0 {
+                match start {
+                    Some(s) if remove_start <= end && remove_end >= s => {
+                        if remove_start > s {
+                            ranges.extend(previous_char(remove_start).map(|p| (s, p)));
+                        }
+                        start = next_char(remove_end);
+                    }
+                    _ => {}
+                }
+            }
+            if let Some(s) = start {
+                if s <= end {
+                    ranges.push((s, end));
+                }
+            }
+        }
+        CharacterSet::new(ranges)
+    }
+
+    /// The characters in both sets
+    fn intersection(&self, other: &CharacterSet) -> Self {
+        self.difference(&self.difference(other))
+    }
+
+    fn contains(&self, c: char) -> bool {
+        self.0
+            .iter()
+            .any(|(start, end)| (*start..=*end).contains(&c))
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// A character from the set, preferring something legible if the set has anything legible in
+    /// it
+    fn candidate(&self) -> String {
+        self.chars()
+            .take(Self::SAMPLE_SIZE as usize)
+            .chain(self.sample())
+            .find(|c| c.is_alphanumeric())
+            .or_else(|| self.chars().next())
+            .map(String::from)
+            .unwrap_or_default()
+    }
+
+    fn len(&self) -> u32 {
+        self.0
+            .iter()
+            .map(|(start, end)| {
+                let len = u32::from(*end) - u32::from(*start) + 1;
+                if *start <= '\u{D7FF}' && *end >= '\u{E000}' {
+                    // Surrogates aren't characters
+                    len - 0x800
+                } else {
+                    len
+                }
+            })
+            .sum()
+    }
+
+    fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        self.0.iter().flat_map(|(start, end)| *start..=*end)
+    }
+
+    /// This set along with the other cases of its letters
+    pub(crate) fn with_other_cases(&self) -> CharacterSet {
+        let others: Vec<_> = self
+            .chars()
+            .flat_map(case_variants)
+            .map(|c| (c, c))
+            .collect();
+        self.union(&CharacterSet::new(others))
+    }
+
+    /// All the characters in the set, or an evenly spread sample if there are too many
+    fn sample(&self) -> Box<dyn Iterator<Item = char> + '_> {
+        let len = self.len();
+        if len <= Self::SAMPLE_SIZE {
+            Box::new(self.chars())
+        } else {
+            let step = (len / Self::SAMPLE_SIZE) as usize;
+            Box::new(self.chars().step_by(step).take(Self::SAMPLE_SIZE as usize))
+        }
+    }
+}
+
+/// The character followed by its other cases, where those are single characters
+pub(crate) fn case_variants(c: char) -> Vec<char> {
+    let mut variants = vec![c];
+    let others: [Vec<_>; 2] = [c.to_lowercase().collect(), c.to_uppercase().collect()];
+    for other in others {
+        if let [other] = other[..] {
+            if !variants.contains(&other) {
+                variants.push(other);
+            }
+        }
+    }
+    variants
+}
+
+fn next_char(c: char) -> Option<char> {
+    match c {
+        '\u{D7FF}' => Some('\u{E000}'),
+        _ => char::from_u32(u32::from(c) + 1),
+    }
+}
+
+fn previous_char(c: char) -> Option<char> {
+    match c {
+        '\u{E000}' => Some('\u{D7FF}'),
+        _ => u32::from(c).checked_sub(1).and_then(char::from_u32),
+    }
 }
 
-impl Expression for ControlFlow {
-    fn example(&self) -> String {
+/// A zero-width assertion about where in the text we are
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub(crate) enum Anchor {
+    StartOfText,
+    EndOfText,
+    /// The end of the text, or just before a newline at the very end
+    EndOfTextOrNewline,
+    StartOfLine,
+    EndOfLine,
+    /// Between a word character and a non-word character (or the edge of the text), where word
+    /// characters are the Unicode ones if `unicode` is set
+    WordBoundary {
+        negated: bool,
+        unicode: bool,
+    },
+}
+
+/// Whether `\w` matches the character
+fn is_word(c: char, unicode: bool) -> bool {
+    if unicode {
+        PERL_WORD
+            .binary_search_by(|(start, end)| {
+                if *end < c {
+                    Ordering::Less
+                } else if *start > c {
+                    Ordering::Greater
+                } else {
+                    Ordering::Equal
+                }
+            })
+            .is_ok()
+    } else {
+        c.is_ascii_alphanumeric() || c == '_'
+    }
+}
+
+impl Anchor {
+    /// Whether the assertion holds at `position`
+    fn holds(self, haystack: &Haystack, position: usize) -> bool {
         match self {
-            ControlFlow::Alternate(v) => v[0].example(),
-            ControlFlow::Optional(e) => e.example(),
-            ControlFlow::Star(e) => e.example(),
-            ControlFlow::Sequence(v) => v.iter().map(|e| e.example()).collect(),
-        }
-    }
-
-    fn enumerate(&self) -> Box<dyn Iterator<Item = String> + '_> {
-        return match self {
-            ControlFlow::Alternate(v) => Box::new(v.iter().flat_map(|e| e.enumerate())),
-            ControlFlow::Optional(e) => Box::new(e.enumerate().chain(["".to_string()])),
-            ControlFlow::Star(e) => Box::new(
-                ["".to_string()].into_iter().chain(e.enumerate()).chain(
-                    e.enumerate()
-                        .cartesian_product(e.enumerate().collect::<Vec<_>>())
-                        .map(|(a, b)| format!("{a}{b}")),
-                ),
-            ),
-            ControlFlow::Sequence(v) => v.iter().fold(
-                Box::new(vec!["".to_string()].into_iter()),
-                |prev: Box<dyn Iterator<Item = String>>, expr| {
-                    Box::new(
-                        prev.cartesian_product(expr.enumerate().collect::<Vec<_>>())
-                            .map(|(a, b)| format!("{a}{b}")),
-                    )
-                },
-            ),
+            Anchor::StartOfText => position == 0,
+            Anchor::EndOfText => position == haystack.len(),
+            Anchor::EndOfTextOrNewline => {
+                position == haystack.len()
+                    || position + 1 == haystack.len() && haystack[position] == '\n'
+            }
+            Anchor::StartOfLine => position == 0 || haystack[position - 1] == '\n',
+            Anchor::EndOfLine => position == haystack.len() || haystack[position] == '\n',
+            Anchor::WordBoundary { negated, unicode } => {
+                let word = |c: Option<&char>| c.is_some_and(|c| is_word(*c, unicode));
+                let before = position.checked_sub(1).and_then(|p| haystack.get(p));
+                (word(before) != word(haystack.get(position))) != negated
+            }
+        }
+    }
+}
+
+/// Which way a lookaround looks from where it is
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub(crate) enum Direction {
+    Ahead,
+    Behind,
+}
+
+impl Direction {
+    /// Whether `e` matches starting at `position` if looking ahead, or ending there if looking
+    /// behind
+    fn holds(self, arena: &Arena, e: Id, haystack: &Haystack, position: usize) -> bool {
+        match self {
+            Direction::Ahead => arena.match_at(e, haystack, position, &|_| true),
+            Direction::Behind => (0..=position)
+                .rev()
+                .any(|start| arena.match_at(e, haystack, start, &|end| end == position)),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod test {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::alphabet::Alphabet;
+    use crate::expression::{Arena, CharacterSet};
+    use crate::parse::{parse, Flags};
+    use crate::tokens::Tokenise;
+    use crate::tree::tree;
+
+    #[rstest]
+    #[case("abc", "a(?:bc)", true)]
+    #[case("abc", "(?:ab)c(?:)", true)]
+    #[case("a|b", "a|(?:b|a)", true)]
+    #[case("a|b", "b|a", false)]
+    #[case("a*", "(?:a*)*", true)]
+    #[case("a", "a{1}", true)]
+    #[case("x", "xa{0}", true)]
+    #[case("a", "(?>a)", true)]
+    #[case("a", "(a)", false)]
+    #[case("b", "a[^ -~]|b", true)]
+    #[case("(?i)ab", "(?i:a)(?i)b", true)]
+    fn test_normalise(#[case] left: &str, #[case] right: &str, #[case] same: bool) {
+        let mut arena = Arena::default();
+        let mut parse = |input: &str| {
+            let tree = tree(input.to_string().tokenise()).expect("Failed to tree");
+            parse(&tree, &Alphabet::default(), Flags::default(), &mut arena)
+                .expect("Failed to parse")
         };
+        let (left, right) = (parse(left), parse(right));
+        assert_eq!(same, left == right);
+    }
+
+    #[rstest]
+    #[case(vec![('a', 'z')], vec![('c', 'd'), ('x', 'x')], vec![('a', 'b'), ('e', 'w'), ('y', 'z')])]
+    #[case(vec![('a', 'c')], vec![('a', 'c')], vec![])]
+    #[case(vec![('a', 'c')], vec![('0', 'b')], vec![('c', 'c')])]
+    #[case(vec![('\0', char::MAX)], vec![('\0', '\u{D7FF}')], vec![('\u{E000}', char::MAX)])]
+    fn test_difference(
+        #[case] set: Vec<(char, char)>,
+        #[case] remove: Vec<(char, char)>,
+        #[case] expected: Vec<(char, char)>,
+    ) {
+        let actual = CharacterSet::new(set).difference(&CharacterSet::new(remove));
+        assert_eq!(CharacterSet::new(expected), actual);
     }
 }
@@ -0,0 +1,315 @@
+use crate::expression::{case_variants, Arena, Direction, Expression, Haystack, Id};
+
+/// Something left to do after part of an expression has matched
+#[derive(Debug, Copy, Clone)]
+enum Frame<'a> {
+    /// Match the rest of a sequence
+    Sequence(&'a [Id]),
+    /// Go round a repetition again, after going round once from `start`.  Only `required`
+    /// repetitions may match the empty string, so that optional ones can't loop forever.
+    Repeat {
+        expression: Id,
+        min: u32,
+        max: Option<u32>,
+        start: usize,
+        required: bool,
+        lazy: bool,
+    },
+    /// Record that group `index` captured the text from `start`
+    Capture { index: usize, start: usize },
+    /// Forget the ways to backtrack found since the backtrack stack was this high, which is how
+    /// atomic groups and possessive quantifiers stick with the first way they find
+    Cut(usize),
+    /// Check that `other` does (or doesn't) match ahead of `start` or behind where the text
+    /// matched so far ends, for an intersection or difference
+    Check {
+        other: Id,
+        direction: Direction,
+        negated: bool,
+        start: usize,
+    },
+}
+
+/// A frame, and the index of the link to go on to after it, or `None` to finish the match
+#[derive(Debug, Copy, Clone)]
+struct Link<'a> {
+    frame: Frame<'a>,
+    next: Option<usize>,
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Step {
+    /// Match an expression at a position, then carry on from the link
+    Match(Id, usize, Option<usize>),
+    /// Carry on from the link, with what's been matched so far ending at the position
+    Continue(usize, Option<usize>),
+}
+
+/// A way to undo part of the match when it fails
+#[derive(Debug)]
+enum Backtrack {
+    /// Try another way from an earlier choice
+    Retry(Step),
+    /// Put back what a group had captured before
+    Restore(usize, Option<(usize, usize)>),
+}
+
+/// A backtracking matcher that keeps its own stacks, so that how far it can go into the text
+/// doesn't depend on the size of the call stack.
+///
+/// What's left to do after each part of the expression is a linked list of frames in `links`,
+/// where the lists for different attempts share their tails.
+#[derive(Debug)]
+pub(super) struct Matcher<'a> {
+    arena: &'a Arena,
+    haystack: &'a Haystack,
+    links: Vec<Link<'a>>,
+    backtrack: Vec<Backtrack>,
+}
+
+impl<'a> Matcher<'a> {
+    pub(super) fn new(arena: &'a Arena, haystack: &'a Haystack) -> Self {
+        Matcher {
+            arena,
+            haystack,
+            links: vec![],
+            backtrack: vec![],
+        }
+    }
+
+    /// Whether `id` matches from `start` to an end that `accept` allows, trying ends in the order
+    /// the expression prefers them
+    pub(super) fn run(mut self, id: Id, start: usize, accept: &dyn Fn(usize) -> bool) -> bool {
+        let mut step = Step::Match(id, start, None);
+        loop {
+            let next = match step {
+                Step::Match(id, position, next) => self.step(id, position, next),
+                Step::Continue(position, None) if accept(position) => return true,
+                Step::Continue(_, None) => None,
+                Step::Continue(position, Some(link)) => self.resume(position, link),
+            };
+            step = match next.or_else(|| self.backtrack()) {
+                Some(step) => step,
+                None => return false,
+            };
+        }
+    }
+
+    /// Starts matching `id` at `position`, returning what to do next or `None` if it can't match
+    fn step(&mut self, id: Id, position: usize, next: Option<usize>) -> Option<Step> {
+        let (arena, haystack) = (self.arena, self.haystack);
+        match arena.get(id) {
+            Expression::Literal(text) => {
+                let mut end = position;
+                for c in text.chars() {
+                    if haystack.get(end) != Some(&c) {
+                        return None;
+                    }
+                    end += 1;
+                }
+                Some(Step::Continue(end, next))
+            }
+            Expression::Set(set) => match haystack.get(position) {
+                Some(c) if set.contains(*c) => Some(Step::Continue(position + 1, next)),
+                _ => None,
+            },
+            Expression::Anchor(anchor) => {
+                (anchor.holds(haystack, position)).then_some(Step::Continue(position, next))
+            }
+            Expression::Lookaround {
+                direction,
+                negated,
+                expression,
+            } => {
+                let holds = direction.holds(arena, *expression, haystack, position);
+                (holds != *negated).then_some(Step::Continue(position, next))
+            }
+            Expression::Intersection {
+                text,
+                other,
+                direction,
+            }
+            | Expression::Difference {
+                text,
+                other,
+                direction,
+            } => {
+                let check = Frame::Check {
+                    other: *other,
+                    direction: *direction,
+                    negated: matches!(arena.get(id), Expression::Difference { .. }),
+                    start: position,
+                };
+                Some(Step::Match(*text, position, self.push(check, next)))
+            }
+            Expression::Alternate(v) => {
+                for e in v[1..].iter().rev() {
+                    self.backtrack
+                        .push(Backtrack::Retry(Step::Match(*e, position, next)));
+                }
+                Some(Step::Match(v[0], position, next))
+            }
+            Expression::Optional(e) => {
+                self.backtrack
+                    .push(Backtrack::Retry(Step::Continue(position, next)));
+                Some(Step::Match(*e, position, next))
+            }
+            Expression::Star(e) => Some(self.repeat(*e, 0, None, position, next, false)),
+            Expression::Plus(e) => Some(self.repeat(*e, 1, None, position, next, false)),
+            Expression::Repeat(e, min, max) => {
+                Some(self.repeat(*e, *min, *max, position, next, false))
+            }
+            Expression::Lazy(e, min, max) => {
+                Some(self.repeat(*e, *min, *max, position, next, true))
+            }
+            Expression::Possessive(e, min, max) => {
+                let cut = self.push(Frame::Cut(self.backtrack.len()), next);
+                Some(self.repeat(*e, *min, *max, position, cut, false))
+            }
+            Expression::Atomic(e) => {
+                let cut = self.push(Frame::Cut(self.backtrack.len()), next);
+                Some(Step::Match(*e, position, cut))
+            }
+            Expression::Sequence(v) => match v.split_first() {
+                None => Some(Step::Continue(position, next)),
+                Some((first, rest)) => {
+                    let rest = self.push(Frame::Sequence(rest), next);
+                    Some(Step::Match(*first, position, rest))
+                }
+            },
+            Expression::Capture { index, expression } => {
+                let capture = Frame::Capture {
+                    index: *index,
+                    start: position,
+                };
+                Some(Step::Match(*expression, position, self.push(capture, next)))
+            }
+            Expression::Backreference {
+                index,
+                case_insensitive,
+                ..
+            } => {
+                // Like most engines, we don't let a group that hasn't captured anything match ""
+                let captured = haystack.captured(*index)?;
+                let end = position + captured.len();
+                let text = haystack.get(position..end)?;
+                let same = |(a, b): (&char, &char)| {
+                    a == b || *case_insensitive && case_variants(*a).contains(b)
+                };
+                (text.iter().zip(captured).all(same)).then_some(Step::Continue(end, next))
+            }
+        }
+    }
+
+    /// Carries on from the frame at `link`, with what's been matched so far ending at `position`
+    fn resume(&mut self, position: usize, link: usize) -> Option<Step> {
+        let Link { frame, next } = self.links[link];
+        match frame {
+            Frame::Sequence(v) => match v.split_first() {
+                None => Some(Step::Continue(position, next)),
+                Some((first, [])) => Some(Step::Match(*first, position, next)),
+                Some((first, rest)) => {
+                    let rest = self.push(Frame::Sequence(rest), next);
+                    Some(Step::Match(*first, position, rest))
+                }
+            },
+            Frame::Repeat {
+                expression,
+                min,
+                max,
+                start,
+                required,
+                lazy,
+            } => {
+                if !required && position == start {
+                    return None;
+                }
+                Some(self.repeat(expression, min, max, position, next, lazy))
+            }
+            Frame::Capture { index, start } => {
+                let previous = self.haystack.capture(index, Some((start, position)));
+                self.backtrack.push(Backtrack::Restore(index, previous));
+                Some(Step::Continue(position, next))
+            }
+            Frame::Cut(height) => {
+                if height < self.backtrack.len() {
+                    // Captures still need putting back if what comes next fails
+                    let restores: Vec<_> = self
+                        .backtrack
+                        .drain(height..)
+                        .filter(|b| matches!(b, Backtrack::Restore(..)))
+                        .collect();
+                    self.backtrack.extend(restores);
+                }
+                Some(Step::Continue(position, next))
+            }
+            Frame::Check {
+                other,
+                direction,
+                negated,
+                start,
+            } => {
+                let at = match direction {
+                    Direction::Ahead => start,
+                    Direction::Behind => position,
+                };
+                let holds = direction.holds(self.arena, other, self.haystack, at);
+                (holds != negated).then_some(Step::Continue(position, next))
+            }
+        }
+    }
+
+    /// Matches between `min` and `max` more repetitions of `e`, trying the most first unless
+    /// `lazy` is set
+    fn repeat(
+        &mut self,
+        e: Id,
+        min: u32,
+        max: Option<u32>,
+        position: usize,
+        next: Option<usize>,
+        lazy: bool,
+    ) -> Step {
+        if max == Some(0) {
+            return Step::Continue(position, next);
+        }
+        let again = |required| Frame::Repeat {
+            expression: e,
+            min: min.saturating_sub(1),
+            max: max.map(|m| m - 1),
+            start: position,
+            required,
+            lazy,
+        };
+        if min > 0 {
+            Step::Match(e, position, self.push(again(true), next))
+        } else if lazy {
+            let again = self.push(again(false), next);
+            self.backtrack
+                .push(Backtrack::Retry(Step::Match(e, position, again)));
+            Step::Continue(position, next)
+        } else {
+            self.backtrack
+                .push(Backtrack::Retry(Step::Continue(position, next)));
+            Step::Match(e, position, self.push(again(false), next))
+        }
+    }
+
+    fn push(&mut self, frame: Frame<'a>, next: Option<usize>) -> Option<usize> {
+        self.links.push(Link { frame, next });
+        Some(self.links.len() - 1)
+    }
+
+    /// Undoes the match back to the last choice, returning the next way to try from there
+    fn backtrack(&mut self) -> Option<Step> {
+        while let Some(entry) = self.backtrack.pop() {
+            match entry {
+                Backtrack::Retry(step) => return Some(step),
+                Backtrack::Restore(index, previous) => {
+                    self.haystack.capture(index, previous);
+                }
+            }
+        }
+        None
+    }
+}
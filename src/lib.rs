@@ -8,7 +8,8 @@
     overflowing_literals,
     path_statements,
     patterns_in_fns_without_body,
-    private_in_public,
+    private_bounds,
+    private_interfaces,
     unconditional_recursion,
     unreachable_pub,
     unused,
@@ -24,40 +25,64 @@
 #[macro_use]
 mod macros;
 
-use anyhow::{Context, Result};
+use anyhow::{anyhow, Context, Result};
 
-use crate::expression::Expression;
-use crate::tokens::Tokenise;
-use crate::tree::tree;
+pub use crate::alphabet::Alphabet;
+pub use crate::dialect::{Dialect, Feature};
+pub use crate::error::{ErrorKind, ParseError, Span, ValueError};
+pub use crate::parse::Flags;
+pub use crate::pattern::Pattern;
+pub use crate::syntax::Syntax;
+pub use crate::tokens::EscapeError;
 
+mod alphabet;
+mod analysis;
+pub mod ast;
+mod dialect;
+mod error;
 mod expression;
 mod parse;
+mod pattern;
+mod syntax;
 mod tokens;
 mod tree;
+mod unicode;
 
 /// Provides an example from the regular language described by the expression
-pub fn example(expression: String) -> Result<()> {
-    let example = parse(expression)
+#[deprecated(note = "use `Pattern::parse_with` and `Pattern::example` instead")]
+pub fn example(expression: String, alphabet: &Alphabet, flags: Flags) -> Result<()> {
+    let example = Pattern::parse_with(&expression, alphabet, flags)
         .context("Failed to parse expression")?
-        .example();
+        .example()
+        .ok_or(anyhow!(
+            "No example found: the expression may not match anything"
+        ))?;
     println!("{example}");
     Ok(())
 }
 
 /// Provides a (non-exhaustive) enumeration of the members of the regular language described by the expression
-pub fn enumerate(expression: String) -> Result<()> {
-    for example in parse(expression)
-        .context("Failed to parse expression")?
-        .enumerate()
-    {
+#[deprecated(note = "use `Pattern::parse_with` and `Pattern::members` instead")]
+pub fn enumerate(expression: String, alphabet: &Alphabet, flags: Flags) -> Result<()> {
+    let pattern =
+        Pattern::parse_with(&expression, alphabet, flags).context("Failed to parse expression")?;
+    for example in pattern.members() {
         println!("{example}")
     }
     Ok(())
 }
 
-fn parse(expression: String) -> Result<Box<dyn Expression>> {
-    let tokens = expression.tokenise();
-    let tree = tree(tokens)?;
-    let expression = parse::parse(&tree)?;
-    Ok(expression)
+/// Reports on how parts of the expression affect what it matches
+#[deprecated(note = "use `Pattern::parse_with` and `Pattern::analyse` instead")]
+pub fn analyse(expression: String, alphabet: &Alphabet, flags: Flags) -> Result<()> {
+    let findings = Pattern::parse_with(&expression, alphabet, flags)
+        .and_then(|pattern| pattern.analyse())
+        .context("Failed to parse expression")?;
+    if findings.is_empty() {
+        println!("Nothing to report");
+    }
+    for finding in findings {
+        println!("{finding}")
+    }
+    Ok(())
 }
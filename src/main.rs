@@ -8,7 +8,8 @@
     overflowing_literals,
     path_statements,
     patterns_in_fns_without_body,
-    private_in_public,
+    private_bounds,
+    private_interfaces,
     unconditional_recursion,
     unreachable_pub,
     unused,
@@ -23,31 +24,121 @@
 
 use std::process::ExitCode;
 
-use anyhow::Result;
-use clap::Parser;
-use regular::{enumerate, example};
+use clap::{Args, Parser};
+use regular::{Alphabet, Dialect, Flags, Pattern, Syntax, ValueError};
 
-fn main() -> Result<ExitCode> {
+fn main() -> ExitCode {
     let args = Opt::parse();
 
-    match args {
-        Opt::Example(o) => example(o.expression),
-        Opt::Enumerate(o) => enumerate(o.expression),
-    }?;
+    let (expression, generation) = match &args {
+        Opt::Example(o) => (&o.expression, &o.generation),
+        Opt::Enumerate(o) => (&o.expression, &o.generation),
+        Opt::Analyse(o) => (&o.expression, &o.generation),
+    };
+    let flags = match generation.flags() {
+        Ok(flags) => flags,
+        Err(error) => {
+            eprintln!("Error: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let pattern = match Pattern::parse_with(expression, &generation.alphabet, flags) {
+        Ok(pattern) => pattern,
+        Err(error) => {
+            eprintln!("{}", error.render(expression));
+            return ExitCode::FAILURE;
+        }
+    };
 
-    Ok(ExitCode::SUCCESS)
+    match args {
+        Opt::Example(_) => match pattern.example() {
+            Some(example) => println!("{example}"),
+            None => {
+                eprintln!("Error: No example found: the expression may not match anything");
+                return ExitCode::FAILURE;
+            }
+        },
+        Opt::Enumerate(_) => {
+            for member in pattern.members() {
+                println!("{member}")
+            }
+        }
+        Opt::Analyse(_) => match pattern.analyse() {
+            Ok(findings) if findings.is_empty() => println!("Nothing to report"),
+            Ok(findings) => {
+                for finding in findings {
+                    println!("{finding}")
+                }
+            }
+            Err(error) => {
+                eprintln!("{}", error.render(expression));
+                return ExitCode::FAILURE;
+            }
+        },
+    }
+    ExitCode::SUCCESS
 }
 
 #[derive(Parser, Debug)]
 struct ExampleOpt {
     /// A regular expression
     expression: String,
+    #[command(flatten)]
+    generation: GenerationOpt,
 }
 
 #[derive(Parser, Debug)]
 struct EnumerateOpt {
     /// A regular expression
     expression: String,
+    #[command(flatten)]
+    generation: GenerationOpt,
+}
+
+#[derive(Parser, Debug)]
+struct AnalyseOpt {
+    /// A regular expression
+    expression: String,
+    #[command(flatten)]
+    generation: GenerationOpt,
+}
+
+#[derive(Args, Debug)]
+struct GenerationOpt {
+    /// Characters for wildcards and negated classes: `ascii`, `unicode`, or a class like `[a-z]`
+    #[arg(long, default_value = "ascii")]
+    alphabet: Alphabet,
+    /// Use the Unicode definitions of `\d`, `\w` and `\s`, rather than the ASCII ones
+    #[arg(long)]
+    unicode: bool,
+    /// Ignore whitespace and `#` comments in the expression, as if it started with `(?x)`
+    #[arg(long)]
+    extended: bool,
+    /// The syntax the expression is written in: `pcre`, `bre`, `ere`, `js`, `python`, `go` or
+    /// `rust`
+    #[arg(long, default_value = "pcre")]
+    dialect: Dialect,
+    /// What kind of pattern the expression is: `regex`, `glob`, or SQL's `like` or `similar-to`
+    #[arg(long, default_value = "regex")]
+    syntax: Syntax,
+    /// The escape character for a SQL pattern, as given by `ESCAPE`
+    #[arg(long)]
+    escape: Option<char>,
+}
+
+impl GenerationOpt {
+    fn flags(&self) -> Result<Flags, ValueError> {
+        Ok(Flags {
+            unicode: self.unicode,
+            extended: self.extended,
+            dialect: self.dialect,
+            syntax: match self.escape {
+                Some(escape) => self.syntax.with_escape(escape)?,
+                None => self.syntax,
+            },
+            ..Flags::default()
+        })
+    }
 }
 
 #[derive(Parser, Debug)]
@@ -59,4 +150,7 @@ enum Opt {
     /// Enumerate the regular language generated by the provided regular expression
     #[arg()]
     Enumerate(EnumerateOpt),
+    /// Report on how parts of the provided regular expression affect what it matches
+    #[arg()]
+    Analyse(AnalyseOpt),
 }
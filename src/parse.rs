@@ -1,51 +1,791 @@
-use anyhow::{anyhow, Result};
-
-use crate::expression::{ControlFlow, Expression, Literal};
-use crate::tree::brackets::Bracket;
+use crate::alphabet::Alphabet;
+use crate::analysis::{is_regular, lower_lookarounds};
+use crate::dialect::Dialect;
+use crate::error::{ParseError, Result};
+use crate::expression::{case_variants, Anchor, Arena, CharacterSet, Direction, Id};
+use crate::syntax::Syntax;
+use crate::tokens::TokenMeta;
+use crate::tree::assertion::Assertion;
+use crate::tree::class::{equivalents, CharacterClass, ClassItem, Shorthand};
+use crate::tree::group::{Flag, Group, Modifiers};
+use crate::tree::reference::Reference;
+use crate::tree::repetition::Repetition;
 use crate::tree::special::Special;
 use crate::tree::Node;
+use crate::unicode::{DECIMAL_NUMBER, PERL_WORD, WHITE_SPACE};
+
+/// Settings that change the meaning of parts of an expression
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub struct Flags {
+    /// `^` and `$` match at the start and end of each line, not just of the whole text
+    pub multi_line: bool,
+    /// `\d`, `\w` and `\s` use their Unicode definitions, rather than being limited to ASCII
+    pub unicode: bool,
+    /// Letters match both their upper and lower case forms
+    pub case_insensitive: bool,
+    /// `.` matches a newline as well
+    pub dot_all: bool,
+    /// Whitespace in the expression is ignored, and `#` starts a comment that runs to the end of
+    /// the line
+    pub extended: bool,
+    /// The flavour of regular expression syntax the expression is written in
+    pub dialect: Dialect,
+    /// Whether the expression is a regular expression, a glob or a SQL pattern
+    pub syntax: Syntax,
+}
 
-pub(crate) fn parse(node: &Node) -> Result<Box<dyn Expression>> {
+impl Flags {
+    /// These flags, changed by a flag group like `(?i-m)`
+    pub(crate) fn with(self, modifiers: &Modifiers) -> Flags {
+        let changes = (modifiers.on.iter().map(|flag| (flag, true)))
+            .chain(modifiers.off.iter().map(|flag| (flag, false)));
+        changes.fold(self, |mut flags, (flag, value)| {
+            match flag {
+                Flag::CaseInsensitive => flags.case_insensitive = value,
+                Flag::MultiLine => flags.multi_line = value,
+                Flag::DotAll => flags.dot_all = value,
+                Flag::Unicode => flags.unicode = value,
+                Flag::Extended => flags.extended = value,
+            }
+            flags
+        })
+    }
+}
+
+/// A capture group found so far, with what's needed to generate the text a backreference repeats
+#[derive(Debug, Clone)]
+pub(crate) struct CaptureGroup {
+    pub(crate) name: Option<String>,
+    content: Node,
+    flags: Flags,
+    /// Whether we're past the end of the group, so that a backreference can repeat it
+    closed: bool,
+}
+
+/// Converts a tree into an expression stored in `arena`
+pub(crate) fn parse(
+    node: &Node,
+    alphabet: &Alphabet,
+    flags: Flags,
+    arena: &mut Arena,
+) -> Result<Id> {
+    // Lowering relies on the parts of the sequence matching independently of each other
+    if !is_regular(node) {
+        return parse_node(node, alphabet, flags, &mut vec![], arena);
+    }
+    let sequence = match node {
+        Node::Sequence(sequence) => sequence.as_slice(),
+        node => std::slice::from_ref(node),
+    };
+    lower_lookarounds(sequence, alphabet, flags, &mut vec![], arena)
+}
+
+/// Converts a node, numbering its capture groups after those already in `captures`
+pub(crate) fn parse_node(
+    node: &Node,
+    alphabet: &Alphabet,
+    flags: Flags,
+    captures: &mut Vec<CaptureGroup>,
+    arena: &mut Arena,
+) -> Result<Id> {
     match node {
-        Node::Empty => Ok(Box::new(Literal("".to_string()))),
-        Node::Sequence(sequence) => Ok(Box::new(ControlFlow::Sequence(
-            sequence.iter().map(parse).collect::<Result<Vec<_>>>()?,
-        ))),
-        Node::Tokens(tokens) => Ok(Box::new(Literal(
-            tokens.iter().map(|t| t.to_char()).collect(),
-        ))),
-        Node::Bracketed(bracket_type, content) => match bracket_type {
-            Bracket::Parentheses => parse(content),
-            Bracket::Braces => {
-                unimplemented!()
+        Node::Empty => Ok(arena.empty()),
+        Node::Sequence(sequence) => {
+            let mut flags = flags;
+            let mut expressions = vec![];
+            for node in sequence {
+                match node {
+                    Node::Group(Group::Flags(modifiers), _) => flags = flags.with(modifiers),
+                    _ => expressions.push(parse_node(node, alphabet, flags, captures, arena)?),
+                }
             }
-            Bracket::Square => {
-                unimplemented!()
+            Ok(arena.sequence(expressions))
+        }
+        Node::Tokens(tokens) if flags.case_insensitive => {
+            let expressions = tokens
+                .iter()
+                .map(|t| {
+                    let variants = case_variants(t.to_char())
+                        .into_iter()
+                        .map(|c| arena.literal(c.to_string()))
+                        .collect();
+                    arena.alternate(variants)
+                })
+                .collect();
+            Ok(arena.sequence(expressions))
+        }
+        Node::Tokens(tokens) => Ok(arena.literal(tokens.iter().map(|t| t.to_char()).collect())),
+        Node::Capture(content) => capture(None, content, alphabet, flags, captures, arena),
+        Node::Group(Group::NonCapturing, content) => {
+            parse_node(content, alphabet, flags, captures, arena)
+        }
+        Node::Group(Group::Scoped(modifiers), content) => {
+            parse_node(content, alphabet, flags.with(modifiers), captures, arena)
+        }
+        Node::Group(Group::Atomic(_), content) => {
+            let content = parse_node(content, alphabet, flags, captures, arena)?;
+            Ok(arena.atomic(content))
+        }
+        Node::Group(Group::Lookahead { negated }, content) => {
+            let content = parse_node(content, alphabet, flags, captures, arena)?;
+            Ok(arena.lookaround(Direction::Ahead, *negated, content))
+        }
+        Node::Group(Group::Lookbehind { negated }, content) => {
+            let content = parse_node(content, alphabet, flags, captures, arena)?;
+            Ok(arena.lookaround(Direction::Behind, *negated, content))
+        }
+        // Flags on their own, which have nothing left in their group to apply to
+        Node::Group(Group::Flags(_), _) => Ok(arena.empty()),
+        Node::Group(Group::Named(name, meta), content) => {
+            if captures.iter().any(|c| c.name.as_ref() == Some(name)) {
+                return Err(ParseError::DuplicateGroupName {
+                    name: name.clone(),
+                    span: (*meta).into(),
+                });
             }
-        },
-        Node::Special(_) => {
-            unimplemented!()
+            capture(
+                Some(name.clone()),
+                content,
+                alphabet,
+                flags,
+                captures,
+                arena,
+            )
+        }
+        Node::Group(Group::Reference(_), _) => Err(ParseError::internal(
+            "A group reference should have formed a Backreference",
+        )),
+        Node::Backreference(reference, meta) => {
+            backreference(reference, meta, alphabet, flags, captures, arena)
         }
+        Node::Class(class) => Ok(arena.set(character_set(class, alphabet, flags))),
+        Node::Repetition(_) => Err(ParseError::internal(
+            "Found a repetition with nothing to repeat",
+        )),
+        Node::Special(Special::Dot) if flags.dot_all => {
+            Ok(arena.set(alphabet.characters().clone()))
+        }
+        Node::Special(Special::Dot) => Ok(arena.set(
+            alphabet
+                .characters()
+                .difference(&CharacterSet::new([('\n', '\n')])),
+        )),
+        Node::Assertion(assertion, _) => Ok(arena.anchor(anchor(*assertion, flags))),
+        Node::Special(special) => Err(ParseError::internal(format!(
+            "Found {special:?} with nothing to apply it to"
+        ))),
         Node::Augmented(content, augmentation) => {
-            let content = parse(content)?;
-            if let Node::Special(Special::Star) = **augmentation {
-                Ok(Box::new(ControlFlow::Star(content)))
-            } else {
-                Err(anyhow!("Unimplemented {augmentation:?}"))
+            let content = parse_node(content, alphabet, flags, captures, arena)?;
+            match **augmentation {
+                Node::Special(Special::Star) => Ok(arena.star(content)),
+                Node::Special(Special::Plus) => Ok(arena.plus(content)),
+                Node::Special(Special::Question) => Ok(arena.optional(content)),
+                Node::Repetition(Repetition { min, max }) => Ok(arena.repeat(content, min, max)),
+                _ => Err(ParseError::internal(format!(
+                    "Unimplemented {augmentation:?}"
+                ))),
             }
         }
-        Node::Alternate(a, b) => {
-            let mut nodes_to_convert = vec![a, b];
+        Node::Lazy(content, quantifier, _) => {
+            let (min, max) = bounds(quantifier)?;
+            let content = parse_node(content, alphabet, flags, captures, arena)?;
+            Ok(arena.lazy(content, min, max))
+        }
+        Node::Possessive(content, quantifier, _) => {
+            let (min, max) = bounds(quantifier)?;
+            let content = parse_node(content, alphabet, flags, captures, arena)?;
+            Ok(arena.possessive(content, min, max))
+        }
+        Node::Alternation(alternatives) => {
             let mut alternates = vec![];
-            while let Some(node) = nodes_to_convert.pop() {
-                if let Node::Alternate(x, y) = &**node {
-                    nodes_to_convert.push(x);
-                    nodes_to_convert.push(y);
+            // Flags set in one alternative carry on into the ones after it
+            let mut flags = flags;
+            for node in alternatives {
+                alternates.push(parse_node(node, alphabet, flags, captures, arena)?);
+                flags = trailing_flags(node, flags);
+            }
+            Ok(arena.alternate(alternates))
+        }
+    }
+}
+
+fn anchor(assertion: Assertion, flags: Flags) -> Anchor {
+    match assertion {
+        Assertion::Caret if flags.multi_line => Anchor::StartOfLine,
+        Assertion::Caret | Assertion::StartOfText => Anchor::StartOfText,
+        Assertion::Dollar if flags.multi_line => Anchor::EndOfLine,
+        Assertion::Dollar | Assertion::EndOfText => Anchor::EndOfText,
+        Assertion::EndOfTextOrNewline => Anchor::EndOfTextOrNewline,
+        Assertion::WordBoundary | Assertion::NotWordBoundary => Anchor::WordBoundary {
+            negated: assertion == Assertion::NotWordBoundary,
+            unicode: flags.unicode,
+        },
+    }
+}
+
+/// The least and most repetitions allowed by a quantifier
+pub(crate) fn bounds(quantifier: &Node) -> Result<(u32, Option<u32>)> {
+    match quantifier {
+        Node::Special(Special::Star) => Ok((0, None)),
+        Node::Special(Special::Plus) => Ok((1, None)),
+        Node::Special(Special::Question) => Ok((0, Some(1))),
+        Node::Repetition(Repetition { min, max }) => Ok((*min, *max)),
+        _ => Err(ParseError::internal(format!(
+            "{quantifier:?} is not a quantifier"
+        ))),
+    }
+}
+
+/// The flags in force at the end of a node, after any flag groups in it
+fn trailing_flags(node: &Node, flags: Flags) -> Flags {
+    match node {
+        Node::Sequence(sequence) => sequence.iter().fold(flags, |f, n| trailing_flags(n, f)),
+        Node::Group(Group::Flags(modifiers), _) => flags.with(modifiers),
+        _ => flags,
+    }
+}
+
+fn capture(
+    name: Option<String>,
+    content: &Node,
+    alphabet: &Alphabet,
+    flags: Flags,
+    captures: &mut Vec<CaptureGroup>,
+    arena: &mut Arena,
+) -> Result<Id> {
+    captures.push(CaptureGroup {
+        name,
+        content: content.clone(),
+        flags,
+        closed: false,
+    });
+    let index = captures.len();
+    let expression = parse_node(content, alphabet, flags, captures, arena)?;
+    captures[index - 1].closed = true;
+    Ok(arena.capture(index, expression))
+}
+
+/// Resolves a backreference to one of the groups before it
+fn backreference(
+    reference: &Reference,
+    meta: &TokenMeta,
+    alphabet: &Alphabet,
+    flags: Flags,
+    captures: &[CaptureGroup],
+    arena: &mut Arena,
+) -> Result<Id> {
+    let index = match reference {
+        Reference::Number(number) => Some(*number).filter(|n| (1..=captures.len()).contains(n)),
+        Reference::Name(name) => captures
+            .iter()
+            .position(|c| c.name.as_ref() == Some(name))
+            .map(|i| i + 1),
+    }
+    .ok_or_else(|| ParseError::MissingGroup {
+        reference: reference.to_string(),
+        span: (*meta).into(),
+    })?;
+    let group = &captures[index - 1];
+    let expression = if group.closed {
+        // Parsing the group again, with the groups inside it numbered as they were the first time
+        let mut earlier = captures[..index].to_vec();
+        earlier[index - 1].closed = false;
+        Some(parse_node(
+            &group.content,
+            alphabet,
+            group.flags,
+            &mut earlier,
+            arena,
+        )?)
+    } else {
+        None
+    };
+    Ok(arena.backreference(index, flags.case_insensitive, expression))
+}
+
+pub(crate) fn character_set(
+    class: &CharacterClass,
+    alphabet: &Alphabet,
+    flags: Flags,
+) -> CharacterSet {
+    let set = class
+        .items
+        .iter()
+        .map(|item| match item {
+            ClassItem::Single(t) => CharacterSet::new([(t.to_char(), t.to_char())]),
+            ClassItem::Range(start, end) => CharacterSet::new([(start.to_char(), end.to_char())]),
+            ClassItem::Shorthand(shorthand) => shorthand_set(*shorthand, alphabet, flags),
+            ClassItem::Property(property) => {
+                let set = CharacterSet::new(property.ranges.iter().copied());
+                if property.negated {
+                    alphabet.characters().difference(&set)
                 } else {
-                    alternates.push(parse(node)?);
+                    set
                 }
             }
-            Ok(Box::new(ControlFlow::Alternate(alternates)))
+            ClassItem::Named(class) => CharacterSet::new(class.ranges().iter().copied()),
+            ClassItem::Equivalence(c) => {
+                CharacterSet::new(equivalents(*c).into_iter().map(|c| (c, c)))
+            }
+        })
+        .fold(CharacterSet::new([]), |a, b| a.union(&b));
+    let set = if flags.case_insensitive {
+        set.with_other_cases()
+    } else {
+        set
+    };
+    if class.negated {
+        alphabet.characters().difference(&set)
+    } else {
+        set
+    }
+}
+
+fn shorthand_set(shorthand: Shorthand, alphabet: &Alphabet, flags: Flags) -> CharacterSet {
+    let (ascii, unicode): (&[(char, char)], _) = match shorthand {
+        Shorthand::Digit | Shorthand::NotDigit => (&[('0', '9')], DECIMAL_NUMBER),
+        Shorthand::Word | Shorthand::NotWord => {
+            (&[('0', '9'), ('A', 'Z'), ('_', '_'), ('a', 'z')], PERL_WORD)
+        }
+        Shorthand::Space | Shorthand::NotSpace => (&[('\t', '\r'), (' ', ' ')], WHITE_SPACE),
+    };
+    let set = CharacterSet::new(if flags.unicode { unicode } else { ascii }.iter().copied());
+    match shorthand {
+        Shorthand::Digit | Shorthand::Word | Shorthand::Space => set,
+        Shorthand::NotDigit | Shorthand::NotWord | Shorthand::NotSpace => {
+            alphabet.characters().difference(&set)
         }
     }
 }
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod test {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::alphabet::Alphabet;
+    use crate::dialect::Dialect;
+    use crate::error::{ErrorKind, Span};
+    use crate::expression::Arena;
+    use crate::parse;
+    use crate::parse::{parse_node, Flags};
+    use crate::pattern::Pattern;
+    use crate::tokens::Tokenise;
+    use crate::tree::tree;
+
+    #[rstest]
+    #[case("[abc]", vec!["a", "b", "c"])]
+    #[case("[a-c]x", vec!["ax", "bx", "cx"])]
+    #[case("[c-da-b]", vec!["a", "b", "c", "d"])]
+    #[case("[]-]", vec!["-", "]"])]
+    #[case("[^!-}]", vec![" ", "~"])]
+    #[case("[\\^\\]]", vec!["]", "^"])]
+    #[case("colou?r", vec!["colour", "color"])]
+    #[case("a+", vec!["a", "aa"])]
+    #[case("[ab]+c", vec!["ac", "bc", "aac", "abc", "bac", "bbc"])]
+    #[case("(ab)?c", vec!["abc", "c"])]
+    #[case("a{2}", vec!["aa"])]
+    #[case("a.", (' '..='~').map(|c| format!("a{c}")).collect())]
+    #[case("\\.", vec!["."])]
+    #[case("[.]", vec!["."])]
+    #[case("\\d", ('0'..='9').map(String::from).collect())]
+    #[case("[\\s]", vec!["\t", "\n", "\u{b}", "\u{c}", "\r", " "])]
+    #[case("[x\\d-]", vec!["-", "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "x"])]
+    #[case("[^\\W\\d]", ('A'..='Z').chain(['_']).chain('a'..='z').map(String::from).collect())]
+    #[case("\\S", ('!'..='~').map(String::from).collect())]
+    #[case("\\x41\\u{42}\\o{103}\\07", vec!["ABC\u{7}"])]
+    #[case("a\\nb", vec!["a\nb"])]
+    #[case("[\\x41-\\x43\\t]", vec!["\t", "A", "B", "C"])]
+    #[case("[[:digit:]]", ('0'..='9').map(String::from).collect())]
+    #[case("[[:blank:]x]", vec!["\t", " ", "x"])]
+    #[case("[^[:graph:]]", vec![" "])]
+    #[case("[[.hyphen.][.space.]]", vec![" ", "-"])]
+    #[case("[[.a.]-c]", vec!["a", "b", "c"])]
+    #[case("[[:punct:]-]", vec!["!", "\"", "#", "$", "%", "&", "'", "(", ")", "*", "+", ",", "-", ".", "/", ":", ";", "<", "=", ">", "?", "@", "[", "\\", "]", "^", "_", "`", "{", "|", "}", "~"])]
+    #[case("\\x64", vec!["d"])]
+    #[case("^ab$", vec!["ab"])]
+    #[case("a^b|c", vec!["c"])]
+    #[case("a$b|c$|$d", vec!["c"])]
+    #[case("(^|x)a", vec!["a", "xa"])]
+    #[case("a|bc|d", vec!["a", "bc", "d"])]
+    #[case("a|b*c", vec!["a", "c", "bc", "bbc"])]
+    #[case("x|a(b)", vec!["x", "ab"])]
+    #[case("a{0,2}", vec!["", "a", "aa"])]
+    #[case("[ab]{2}", vec!["aa", "ab", "ba", "bb"])]
+    #[case("a{2,}", vec!["aa", "aaa", "aaaa"])]
+    #[case("xa{0}", vec!["x"])]
+    #[case("a{,2}", vec!["a{,2}"])]
+    #[case("a{x}", vec!["a{x}"])]
+    #[case("a{1", vec!["a{1"])]
+    #[case("{2}", vec!["{2}"])]
+    #[case("a}", vec!["a}"])]
+    #[case("[0-1]{2}-[0-1]", vec!["00-0", "00-1", "01-0", "01-1", "10-0", "10-1", "11-0", "11-1"])]
+    #[case("(?:ab)+", vec!["ab", "abab"])]
+    #[case("(?P<x>a|b)c", vec!["ac", "bc"])]
+    #[case("(?<x>a)(?'y'b)", vec!["ab"])]
+    #[case("(?:a|(?:b|c))d", vec!["ad", "bd", "cd"])]
+    #[case("(?i)ab", vec!["ab", "aB", "Ab", "AB"])]
+    #[case("a*?b", vec!["b", "ab", "aab"])]
+    #[case("(?=a)\\w", vec!["a"])]
+    #[case("(?!a)[ab]", vec!["b"])]
+    #[case("[ab](?<=b)", vec!["b"])]
+    #[case("[ab](?<!b)", vec!["a"])]
+    #[case("(?=.*1)[01]{2}", vec!["10", "01", "11"])]
+    #[case("x(?:(?=a)[ab])", vec!["xa"])]
+    #[case("(a(?=b)|c)b", vec!["ab", "cb"])]
+    #[case("(?<=a)b", Vec::<&str>::new())]
+    #[case("a(?=b)", Vec::<&str>::new())]
+    #[case("a{1,2}?", vec!["a", "aa"])]
+    #[case("a??a", vec!["a", "aa"])]
+    #[case("a*+a", Vec::<&str>::new())]
+    #[case("a?+b", vec!["b", "ab"])]
+    #[case("(?>a|ab)c", vec!["ac"])]
+    #[case("(?>ab|a)c", vec!["abc", "ac"])]
+    #[case("a(?i:b)c", vec!["abc", "aBc"])]
+    #[case("(?i)a(?-i)b", vec!["ab", "Ab"])]
+    #[case("(?i)[a-b1]", vec!["1", "A", "B", "a", "b"])]
+    #[case("x(?i)a|b", vec!["xa", "xA", "b", "B"])]
+    #[case("(a(?i))b", vec!["ab"])]
+    #[case("(?m)a$\n^b", vec!["a\nb"])]
+    #[case("a$\n(?m)^b", Vec::<&str>::new())]
+    #[case("(a|b)\\1", vec!["aa", "bb"])]
+    #[case("(?x) a b # c\n [ ]\\ d", vec!["ab  d"])]
+    #[case("a(?x: b c )d e", vec!["abcd e"])]
+    #[case("\\Aa\\z", vec!["a"])]
+    #[case("a\\Z\\n?", vec!["a\n", "a"])]
+    #[case("a\\b[ -]?", vec!["a ", "a-", "a"])]
+    #[case("a\\B[ab]?", vec!["aa", "ab"])]
+    #[case("a\\bb|\\b", Vec::<&str>::new())]
+    #[case("(?<x>a|b)c\\k<x>", vec!["aca", "bcb"])]
+    #[case("(?P<x>[ab])(?P=x)", vec!["aa", "bb"])]
+    #[case("((a)|b)\\2", vec!["aa"])]
+    #[case("(a\\1)", Vec::<&str>::new())]
+    #[case("(?i)(a)\\1", vec!["aa", "aA", "Aa", "AA"])]
+    #[case("(?=a)(a|b)\\1", vec!["aa"])]
+    #[case("a^b.{4}|c", vec!["c"])]
+    #[case("(?=a)b.{4}|c", vec!["c"])]
+    #[case("a(?<=b).{4}|c", vec!["c"])]
+    fn test_enumerate<S: AsRef<str>>(#[case] input: &str, #[case] expected: Vec<S>) {
+        let expression = Pattern::parse_with(input, &Alphabet::default(), Flags::default())
+            .expect("Failed to parse");
+        let actual: Vec<_> = expression.members().collect();
+        let expected: Vec<_> = expected.iter().map(AsRef::as_ref).collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[rstest]
+    #[case(".", "[xy]", vec!["x", "y"])]
+    #[case("[^x]", "[xy]", vec!["y"])]
+    #[case(".", "[\n]", vec![])]
+    #[case("(?s).", "[\n]", vec!["\n"])]
+    #[case("(?s:.)(?-s).?", "[\n]", vec!["\n"])]
+    #[case("(?i)[^a]", "[aAb]", vec!["b"])]
+    #[case(".?\\bfoo\\b.?", "[x ]", vec![" foo ", " foo", "foo ", "foo"])]
+    fn test_alphabet(#[case] input: &str, #[case] alphabet: &str, #[case] expected: Vec<&str>) {
+        let alphabet: Alphabet = alphabet.parse().expect("Failed to parse alphabet");
+        let expression =
+            Pattern::parse_with(input, &alphabet, Flags::default()).expect("Failed to parse");
+        let actual: Vec<_> = expression.members().collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[rstest]
+    #[case("a$\n^b", vec!["a\nb"])]
+    #[case("a$[\n]?^b", vec!["a\nb"])]
+    #[case("a^b", vec![])]
+    fn test_multi_line(#[case] input: &str, #[case] expected: Vec<&str>) {
+        let tree = tree(input.chars().tokenise()).expect("Failed to tree");
+        let flags = Flags {
+            multi_line: true,
+            ..Flags::default()
+        };
+        let mut arena = Arena::default();
+        let expression =
+            parse::parse(&tree, &Alphabet::default(), flags, &mut arena).expect("Failed to parse");
+        let actual: Vec<_> = arena.enumerate(expression).collect();
+        assert_eq!(expected, actual);
+        assert_eq!(
+            expected.first().map(|e| e.to_string()),
+            arena.example(expression)
+        );
+    }
+
+    #[rstest]
+    #[case("^ \\d{3} # area code\n - \\d{4} $", "123-4567", true)]
+    #[case("a b", "a b", false)]
+    #[case("(?-x)a b", "a b", true)]
+    fn test_extended(#[case] input: &str, #[case] text: &str, #[case] expected: bool) {
+        let flags = Flags {
+            extended: true,
+            ..Flags::default()
+        };
+        let expression =
+            Pattern::parse_with(input, &Alphabet::default(), flags).expect("Failed to parse");
+        assert_eq!(expected, expression.is_match(text));
+    }
+
+    #[rstest]
+    #[case(Dialect::PosixBasic, "\\(ab\\)\\1", "abab", true)]
+    #[case(Dialect::PosixBasic, "(ab)", "(ab)", true)]
+    #[case(Dialect::PosixBasic, "a+", "a+", true)]
+    #[case(Dialect::PosixBasic, "a\\{2\\}", "aa", true)]
+    #[case(Dialect::PosixBasic, "a\\|b", "b", true)]
+    #[case(Dialect::PosixBasic, "\\(?:a\\)", "?:a", true)]
+    #[case(Dialect::PosixExtended, "(ab)+", "abab", true)]
+    #[case(Dialect::JavaScript, "a+?", "aa", true)]
+    #[case(Dialect::PosixExtended, "[[=e=]]+", "éè", true)]
+    #[case(Dialect::PosixExtended, "[[:alpha:][:digit:]]+", "a1", true)]
+    #[case(Dialect::PosixExtended, "[\\n]", "\\", true)]
+    #[case(Dialect::PosixExtended, "[\\n]", "n", true)]
+    #[case(Dialect::PosixExtended, "[\\n]", "\n", false)]
+    #[case(Dialect::PosixExtended, "[\\d]", "d", true)]
+    #[case(Dialect::PosixExtended, "[\\]", "\\", true)]
+    #[case(Dialect::PosixExtended, "[]a]", "]", true)]
+    #[case(Dialect::PosixBasic, "[\\(]\\(a\\)", "\\a", true)]
+    #[case(Dialect::PosixBasic, "*a", "*a", true)]
+    #[case(Dialect::PosixBasic, "\\(*a\\)", "*a", true)]
+    #[case(Dialect::PosixBasic, "^*", "*", true)]
+    #[case(Dialect::PosixBasic, "a^b", "a^b", true)]
+    #[case(Dialect::PosixBasic, "a$b", "a$b", true)]
+    #[case(Dialect::PosixBasic, "^^a$$", "^a$", true)]
+    #[case(Dialect::PosixBasic, "\\(^a\\)b", "ab", true)]
+    #[case(Dialect::PosixBasic, "\\(a$\\)b", "ab", false)]
+    #[case(Dialect::PosixBasic, "a$\\|^b", "b", true)]
+    #[case(Dialect::PosixExtended, "a^b", "a^b", false)]
+    #[case(Dialect::Python, "a\\Z", "a", true)]
+    #[case(Dialect::Python, "a\\Z\n", "a\n", false)]
+    #[case(Dialect::Pcre, "a\\Z\n", "a\n", true)]
+    #[case(Dialect::Rust, "a\\z", "a", true)]
+    #[case(Dialect::Python, "(?P<x>a)(?P=x)", "aa", true)]
+    #[case(Dialect::PosixBasic, "a*", "aa", true)]
+    #[case(Dialect::Pcre, "[\\n]", "\n", true)]
+    #[case(Dialect::Pcre, "\\101", "A", true)]
+    #[case(Dialect::Pcre, "\\1012", "A2", true)]
+    #[case(Dialect::Pcre, "(a)\\12", "a\n", true)]
+    #[case(
+        Dialect::Pcre,
+        "(a)(b)(c)(d)(e)(f)(g)(h)(i)(j)\\10",
+        "abcdefghijj",
+        true
+    )]
+    #[case(Dialect::Pcre, "[\\1]", "\u{1}", true)]
+    #[case(Dialect::Pcre, "(a)[\\1]", "aa", false)]
+    fn test_dialect(
+        #[case] dialect: Dialect,
+        #[case] input: &str,
+        #[case] text: &str,
+        #[case] expected: bool,
+    ) {
+        let flags = Flags {
+            dialect,
+            ..Flags::default()
+        };
+        let expression =
+            Pattern::parse_with(input, &Alphabet::default(), flags).expect("Failed to parse");
+        assert_eq!(expected, expression.is_match(text));
+    }
+
+    #[rstest]
+    #[case(Dialect::Go, "a(?=b)", "Lookahead at position 1 isn't supported in Go")]
+    #[case(
+        Dialect::PosixExtended,
+        "a*?",
+        "Lazy quantifier at position 2 isn't supported in POSIX extended regular expressions"
+    )]
+    #[case(
+        Dialect::PosixExtended,
+        "(?:a)",
+        "Non-capturing group at position 0 isn't supported in POSIX extended regular expressions"
+    )]
+    #[case(
+        Dialect::Rust,
+        "(a)\\1",
+        "Backreference at position 3 isn't supported in Rust"
+    )]
+    #[case(
+        Dialect::JavaScript,
+        "\\Aa",
+        "Start of text anchor at position 0 isn't supported in JavaScript"
+    )]
+    #[case(
+        Dialect::Rust,
+        "a\\Z",
+        "End of text or final newline anchor at position 1 isn't supported in Rust"
+    )]
+    #[case(
+        Dialect::Go,
+        "a\\Z",
+        "End of text or final newline anchor at position 1 isn't supported in Go"
+    )]
+    #[case(Dialect::Python, "a\\z", "Unexpected \\z at position 1")]
+    #[case(Dialect::Python, "(?<x>a)", "Unknown group syntax (?<x> at position 0")]
+    #[case(
+        Dialect::Python,
+        "[\\p{L}]",
+        "Unicode property at position 1 isn't supported in Python"
+    )]
+    #[case(
+        Dialect::JavaScript,
+        "[[:alpha:]]",
+        "POSIX character class at position 1 isn't supported in JavaScript"
+    )]
+    #[case(
+        Dialect::Go,
+        "[[=e=]]",
+        "Collating element at position 1 isn't supported in Go"
+    )]
+    #[case(
+        Dialect::PosixExtended,
+        "a++",
+        "Possessive quantifier at position 2 isn't supported in POSIX extended regular expressions"
+    )]
+    fn test_unsupported(#[case] dialect: Dialect, #[case] input: &str, #[case] expected: &str) {
+        let flags = Flags {
+            dialect,
+            ..Flags::default()
+        };
+        let error =
+            Pattern::parse_with(input, &Alphabet::default(), flags).expect_err("Should not parse");
+        assert_eq!(expected, format!("{error}"));
+    }
+
+    #[rstest]
+    #[case("\\d", "٣", true)]
+    #[case("\\w", "é", true)]
+    #[case("\\s", "\u{2003}", true)]
+    #[case("\\D", "٣", false)]
+    #[case("[\\w]", "ß", true)]
+    fn test_unicode_shorthand(#[case] input: &str, #[case] text: &str, #[case] expected: bool) {
+        let tree = tree(input.chars().tokenise()).expect("Failed to tree");
+        let flags = Flags {
+            unicode: true,
+            ..Flags::default()
+        };
+        let mut arena = Arena::default();
+        let expression =
+            parse::parse(&tree, &Alphabet::unicode(), flags, &mut arena).expect("Failed to parse");
+        assert_eq!(expected, arena.is_match(expression, text));
+        let ascii = parse::parse(&tree, &Alphabet::unicode(), Flags::default(), &mut arena)
+            .expect("Failed to parse");
+        assert_eq!(!expected, arena.is_match(ascii, text));
+    }
+
+    #[rstest]
+    #[case("\\p{Greek}+", "λόγος", true)]
+    #[case("\\pL+", "λόγος", true)]
+    #[case("\\p{Greek}", "a", false)]
+    #[case("\\P{Greek}", "a", true)]
+    #[case("\\p{^Greek}", "a", true)]
+    #[case("[\\p{Lu}\\d]+", "A1", true)]
+    #[case("[^\\p{Lu}]", "A", false)]
+    #[case("[\\P{Lu}]", "a", true)]
+    fn test_property(#[case] input: &str, #[case] text: &str, #[case] expected: bool) {
+        let expression = Pattern::parse_with(input, &Alphabet::unicode(), Flags::default())
+            .expect("Failed to parse");
+        assert_eq!(expected, expression.is_match(text));
+    }
+
+    #[rstest]
+    #[case("ab\\x4", "Malformed escape at position 2: expected more digits")]
+    #[case(
+        "[a-\\u{110000}]",
+        "Malformed escape at position 3: 0x110000 is not a valid character"
+    )]
+    #[case("a\\", "Escape character at position 1 has nothing to escape")]
+    #[case("a(?Q)", "Unknown group syntax (?Q at position 1")]
+    #[case(
+        "(?<x>a)(?P<x>b)",
+        "There's more than one group named \"x\", the second at position 7"
+    )]
+    #[case("(?:a", "Unclosed ( at position 0")]
+    #[case("(?P<x", "Unclosed ( at position 0")]
+    #[case("(?iq)", "Unknown flag 'q' at position 3")]
+    #[case("a(b]", "Mismatched brackets: ( closed by ] at position 3")]
+    #[case("a)", "Unmatched ) at position 1")]
+    #[case("a|*", "Nothing to repeat before * at position 2")]
+    #[case("(a|b", "Unclosed ( at position 0")]
+    #[case("a[b", "Unclosed [ at position 1")]
+    #[case("a]", "Unmatched ] at position 1")]
+    #[case(
+        &"(".repeat(1000),
+        "Expression is nested more than 200 deep at position 200"
+    )]
+    #[case(
+        &format!("a{}", "*".repeat(1000)),
+        "Expression is nested more than 200 deep at position 200"
+    )]
+    #[case("\\1(a)", "There's no group 1 before the backreference at position 0")]
+    #[case(
+        "(a)\\k<b>",
+        "There's no group \"b\" before the backreference at position 3"
+    )]
+    #[case("[\\k<x>]", "Unexpected \\k<x> at position 1")]
+    #[case("[[:alfa:]]", "Unknown character class [:alfa:] at position 1")]
+    #[case("[[=ch=]]", "Unknown collating element \"ch\" at position 1")]
+    #[case("a{3,2}", "Repetition {3,2} at position 1 has its bounds out of order")]
+    #[case(
+        "ab{99999999999}",
+        "Invalid repetition count 99999999999 at position 2: number too large to fit in target type"
+    )]
+    fn test_malformed(#[case] input: &str, #[case] expected: &str) {
+        let error = Pattern::parse_with(input, &Alphabet::default(), Flags::default())
+            .expect_err("Should not parse");
+        assert_eq!(expected, format!("{error}"));
+    }
+
+    #[rstest]
+    #[case("ab\\x4", ErrorKind::MalformedEscape, Span::new(2, 3))]
+    #[case("[b-a]", ErrorKind::InvalidRange, Span::new(1, 4))]
+    #[case("a(?Q)", ErrorKind::UnknownGroup, Span::new(1, 4))]
+    #[case("(?<x>a)(?<x>b)", ErrorKind::DuplicateGroupName, Span::new(7, 8))]
+    #[case("a{3,2}", ErrorKind::RepetitionOutOfOrder, Span::new(1, 6))]
+    #[case("(a)\\2", ErrorKind::MissingGroup, Span::new(3, 4))]
+    fn test_error_kind(#[case] input: &str, #[case] kind: ErrorKind, #[case] span: Span) {
+        let error = tree(input.chars().tokenise())
+            .and_then(|tree| {
+                let mut arena = Arena::default();
+                parse::parse(&tree, &Alphabet::default(), Flags::default(), &mut arena)
+            })
+            .expect_err("Should not parse");
+        assert_eq!((kind, span), (error.kind(), error.span()));
+    }
+
+    #[rstest]
+    #[case("(a)(?:b)(c)", vec![None, None])]
+    #[case("((?P<x>a)b)(?<y>c)", vec![None, Some("x"), Some("y")])]
+    fn test_captures(#[case] input: &str, #[case] expected: Vec<Option<&str>>) {
+        let tree = tree(input.to_string().tokenise()).expect("Failed to tree");
+        let mut captures = vec![];
+        let mut arena = Arena::default();
+        parse_node(
+            &tree,
+            &Alphabet::default(),
+            Flags::default(),
+            &mut captures,
+            &mut arena,
+        )
+        .expect("Failed to parse");
+        let actual: Vec<_> = captures.iter().map(|c| c.name.as_deref()).collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[rstest]
+    #[case("(?=.*\\d)(?=.*[a-z])(?=.*[A-Z]).{8,}")]
+    #[case("^(?=.*\\d)(?!.*\\s)(?=.*[a-z])(?=.*[A-Z])(?=.*[!@#$%]).{8,16}$")]
+    #[case("^(?=[^a-z]*[a-z])(?=\\D*\\d)\\w{6}$")]
+    fn test_password_policy(#[case] input: &str) {
+        let expression = Pattern::parse_with(input, &Alphabet::default(), Flags::default())
+            .expect("Failed to parse");
+        let example = expression.example().expect("Should have an example");
+        assert!(expression.is_match(&example), "{example:?} doesn't match");
+    }
+
+    #[rstest]
+    fn test_unicode_sample() {
+        let expression = Pattern::parse_with(".", &Alphabet::unicode(), Flags::default())
+            .expect("Failed to parse");
+        assert_eq!(256, expression.members().count());
+        assert_eq!(Some("0".to_string()), expression.example());
+    }
+}
@@ -0,0 +1,192 @@
+use crate::alphabet::Alphabet;
+use crate::analysis;
+use crate::ast;
+use crate::error::{ParseError, Result};
+use crate::expression::{Arena, Id};
+use crate::parse::{parse, Flags};
+use crate::syntax::glob::glob;
+use crate::syntax::sql::{like, similar_to};
+use crate::syntax::Syntax;
+use crate::tokens::verbose::Verbose;
+use crate::tokens::Tokenise;
+use crate::tree::{tree_in, Node};
+
+/// A parsed expression, which generates the strings it matches.
+///
+/// Patterns can be shared between threads, and generate what they match lazily.
+///
+/// ```
+/// use regular::Pattern;
+///
+/// let pattern = Pattern::parse("colou?r").unwrap();
+/// assert_eq!(Some("colour".to_string()), pattern.example());
+/// assert_eq!(vec!["colour", "color"], pattern.members().collect::<Vec<_>>());
+/// ```
+#[derive(Debug)]
+pub struct Pattern {
+    ast: ast::Node,
+    tree: Node,
+    arena: Arena,
+    expression: Id,
+    alphabet: Alphabet,
+    flags: Flags,
+}
+
+impl Pattern {
+    /// Parses a regular expression, with the default flags and printable ASCII for wildcards and
+    /// negated classes
+    pub fn parse(expression: &str) -> std::result::Result<Pattern, ParseError> {
+        Pattern::parse_with(expression, &Alphabet::default(), Flags::default())
+    }
+
+    /// Parses an expression in whichever syntax `flags` picks, with `alphabet` for wildcards and
+    /// negated classes
+    pub fn parse_with(
+        expression: &str,
+        alphabet: &Alphabet,
+        flags: Flags,
+    ) -> std::result::Result<Pattern, ParseError> {
+        let tree = parse_tree(expression, flags)?;
+        Pattern::from_tree(tree, alphabet, flags)
+    }
+
+    /// Builds a pattern from an AST, such as one rewritten with [`Fold`](crate::ast::Fold).
+    ///
+    /// The pattern is the AST written out as a regular expression in the dialect `flags` picks, so
+    /// that positions in what [`analyse`](Pattern::analyse) reports are positions in that text.
+    /// Fails if the dialect can't express part of the AST.
+    pub fn from_ast(
+        ast: &ast::Node,
+        alphabet: &Alphabet,
+        flags: Flags,
+    ) -> std::result::Result<Pattern, ParseError> {
+        let flags = Flags {
+            syntax: Syntax::Regex,
+            extended: false,
+            ..flags
+        };
+        Pattern::parse_with(&ast.to_pattern(flags.dialect)?, alphabet, flags)
+    }
+
+    fn from_tree(tree: Node, alphabet: &Alphabet, flags: Flags) -> Result<Pattern> {
+        let mut arena = Arena::default();
+        let expression = parse(&tree, alphabet, flags, &mut arena)?;
+        Ok(Pattern {
+            ast: (&tree).try_into()?,
+            tree,
+            arena,
+            expression,
+            alphabet: alphabet.clone(),
+            flags,
+        })
+    }
+
+    /// The structure of the pattern
+    pub fn ast(&self) -> &ast::Node {
+        &self.ast
+    }
+
+    /// A string that the pattern matches, or `None` if it doesn't match anything or the search
+    /// for one gives up before finding it
+    pub fn example(&self) -> Option<String> {
+        self.arena.example(self.expression)
+    }
+
+    /// The strings that the pattern matches, which may go on forever
+    pub fn members(&self) -> impl Iterator<Item = String> + '_ {
+        self.arena.enumerate(self.expression)
+    }
+
+    /// Whether the pattern matches the whole of `text`
+    pub fn is_match(&self, text: &str) -> bool {
+        self.arena.is_match(self.expression, text)
+    }
+
+    /// Notes on how parts of the pattern affect what it matches
+    pub fn analyse(&self) -> std::result::Result<Vec<String>, ParseError> {
+        analysis::analyse(&self.tree, &self.alphabet, self.flags)
+    }
+}
+
+fn parse_tree(expression: &str, flags: Flags) -> Result<Node> {
+    match flags.syntax {
+        Syntax::Regex => {
+            let tokens = expression.chars().tokenise().in_dialect(flags.dialect);
+            tree_in(Verbose::new(tokens, flags.extended), flags.dialect)
+        }
+        Syntax::Glob => glob(expression),
+        Syntax::Like { escape } => like(expression, escape),
+        Syntax::SimilarTo { escape } => similar_to(expression, escape),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod test {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::alphabet::Alphabet;
+    use crate::parse::Flags;
+    use crate::pattern::Pattern;
+
+    #[rstest]
+    fn test_shared() {
+        fn assert_send_sync<T: Send + Sync>(_: &T) {}
+
+        let pattern = Pattern::parse("[ab]{2}").expect("Failed to parse");
+        assert_send_sync(&pattern);
+        let members = std::thread::scope(|scope| {
+            let handle = scope.spawn(|| pattern.members().collect::<Vec<_>>());
+            handle.join().expect("Thread panicked")
+        });
+        assert_eq!(vec!["aa", "ab", "ba", "bb"], members);
+    }
+
+    #[rstest]
+    #[case("a+", Some("a"))]
+    #[case("a(?=b)", None)]
+    #[case("a^b.{4}", None)]
+    #[case("a$b.{4}", None)]
+    #[case("x(?:.{6}(?=~))", None)]
+    #[case("x(?i:.(?=B))b", Some("x b"))]
+    #[case("(?:a(?<=a))b", Some("ab"))]
+    #[case("x(.{6}(?=~))", None)]
+    fn test_example(#[case] input: &str, #[case] expected: Option<&str>) {
+        let pattern = Pattern::parse(input).expect("Failed to parse");
+        assert_eq!(expected.map(String::from), pattern.example());
+    }
+
+    #[rstest]
+    #[case("(?x)a # \\u{ oops\nb", false)]
+    #[case("a # \\x{ oops\nb", true)]
+    #[case("a # \\k<x\nb", true)]
+    fn test_extended_comment(#[case] input: &str, #[case] extended: bool) {
+        let flags = Flags {
+            extended,
+            ..Flags::default()
+        };
+        let pattern =
+            Pattern::parse_with(input, &Alphabet::default(), flags).expect("Failed to parse");
+        assert_eq!(vec!["ab"], pattern.members().collect::<Vec<_>>());
+    }
+
+    #[rstest]
+    #[case("a*", "a".repeat(200_000), true)]
+    #[case("a{20000}", "a".repeat(20_000), true)]
+    #[case("a{20000}", "a".repeat(19_999), false)]
+    #[case("(a{100}){100}", "a".repeat(10_000), true)]
+    #[case("(?:a|b)*c", "ab".repeat(50_000), false)]
+    #[case("(a*)+b\\1", format!("{}b{}", "a".repeat(10_000), "a".repeat(10_000)), true)]
+    fn test_long_text(#[case] input: &str, #[case] text: String, #[case] expected: bool) {
+        // The matcher shouldn't need more stack the longer the text is
+        let pattern = Pattern::parse(input).expect("Failed to parse");
+        assert_eq!(expected, pattern.is_match(&text));
+    }
+
+    #[rstest]
+    fn test_long_example() {
+        let pattern = Pattern::parse("a{100000}").expect("Failed to parse");
+        assert_eq!(Some("a".repeat(100_000)), pattern.example());
+    }
+}
@@ -0,0 +1,113 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::error::ValueError;
+use crate::tokens::{Token, TokenMeta};
+use crate::tree::class::closes_class;
+use crate::tree::special::Special;
+use crate::tree::Node;
+
+pub(crate) mod glob;
+pub(crate) mod sql;
+
+/// The language an expression is written in, each of which is read into the same tree
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum Syntax {
+    /// A regular expression, in whichever [`Dialect`](crate::Dialect) is chosen
+    #[default]
+    Regex,
+    /// A shell glob, as used in `.gitignore`, where `*` and `?` don't match `/`
+    Glob,
+    /// A SQL `LIKE` pattern, where `%` matches any text and `_` any one character
+    Like {
+        /// The character that makes the one after it literal, as given by `ESCAPE`
+        escape: Option<char>,
+    },
+    /// A SQL `SIMILAR TO` pattern, which adds alternatives, groups, classes and quantifiers to
+    /// `LIKE` patterns
+    SimilarTo {
+        /// The character that makes the one after it literal, as given by `ESCAPE`
+        escape: Option<char>,
+    },
+}
+
+impl Syntax {
+    /// This syntax, with `escape` as the escape character, which only the SQL ones have
+    pub fn with_escape(self, escape: char) -> Result<Syntax, ValueError> {
+        match self {
+            Syntax::Like { .. } => Ok(Syntax::Like {
+                escape: Some(escape),
+            }),
+            Syntax::SimilarTo { .. } => Ok(Syntax::SimilarTo {
+                escape: Some(escape),
+            }),
+            Syntax::Regex | Syntax::Glob => Err(ValueError::NoEscape { syntax: self }),
+        }
+    }
+}
+
+impl Display for Syntax {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Syntax::Regex => "regular expression",
+            Syntax::Glob => "glob",
+            Syntax::Like { .. } => "LIKE pattern",
+            Syntax::SimilarTo { .. } => "SIMILAR TO pattern",
+        })
+    }
+}
+
+/// Accepts `regex`, `glob`, `like` or `similar-to`, where the SQL syntaxes have no escape character
+impl FromStr for Syntax {
+    type Err = ValueError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, ValueError> {
+        match s.to_ascii_lowercase().as_str() {
+            "regex" | "regexp" => Ok(Syntax::Regex),
+            "glob" => Ok(Syntax::Glob),
+            "like" => Ok(Syntax::Like { escape: None }),
+            "similar-to" | "similar" => Ok(Syntax::SimilarTo { escape: None }),
+            _ => Err(ValueError::Unknown {
+                expected: "`regex`, `glob`, `like` or `similar-to`",
+                found: s.to_string(),
+            }),
+        }
+    }
+}
+
+/// Repeats `node` any number of times
+fn star(node: Node) -> Node {
+    Node::Augmented(Box::new(node), Box::new(Node::Special(Special::Star)))
+}
+
+/// The tokens of the square-bracket class starting at `start`, and where the expression carries on
+/// after it, if it's closed before `end`.
+///
+/// `escape` makes the character after it literal, and a leading `negation` becomes the `^` that
+/// [`CharacterClass`](crate::tree::class::CharacterClass) expects.
+fn class_body(
+    chars: &[char],
+    start: usize,
+    end: usize,
+    escape: Option<char>,
+    negation: char,
+) -> Option<(Vec<Token>, usize)> {
+    let mut body = vec![];
+    let mut i = start + 1;
+    while i < end {
+        let meta = TokenMeta { position: i };
+        let token = match chars[i] {
+            ']' if closes_class(&body) => return Some((body, i + 1)),
+            c if Some(c) == escape && i + 1 < end => {
+                i += 1;
+                Token::Encoded(chars[i], meta)
+            }
+            c if c == negation && body.is_empty() => Token::Raw('^', meta),
+            c => Token::Raw(c, meta),
+        };
+        body.push(token);
+        i += 1;
+    }
+    None
+}
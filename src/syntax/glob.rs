@@ -0,0 +1,253 @@
+use crate::error::{ParseError, Result};
+use crate::syntax::{class_body, star};
+use crate::tokens::{Token, TokenMeta};
+use crate::tree::class::{CharacterClass, ClassItem};
+use crate::tree::group::Group;
+use crate::tree::{height, Node, MAX_DEPTH};
+
+/// Builds the tree for a glob.
+///
+/// `*` and `?` match anything but `/`, and `[!..]` never matches `/`.  `**` as a whole path
+/// segment matches any number of directories, or at the end of the glob, anything at all.  `{a,b}`
+/// matches either alternative, and a `[` or `{` that isn't closed is literal.
+pub(crate) fn glob(expression: &str) -> Result<Node> {
+    let chars: Vec<_> = expression.chars().collect();
+    sequence(&chars, 0, chars.len(), 0)
+}
+
+/// Builds the tree for the part of the glob from `start` to `end`, which is inside `depth` sets
+/// of braces
+fn sequence(chars: &[char], start: usize, end: usize, depth: usize) -> Result<Node> {
+    let mut nodes = vec![];
+    let mut literal = vec![];
+    let mut i = start;
+    while i < end {
+        let meta = TokenMeta { position: i };
+        let node = match chars[i] {
+            '\\' if i + 1 < end => {
+                literal.push(Token::Raw(chars[i + 1], meta));
+                i += 2;
+                continue;
+            }
+            '\\' => return Err(ParseError::TrailingEscape { span: meta.into() }),
+            '*' if chars[i + 1..end].first() == Some(&'*') && starts_segment(chars, i) => {
+                match chars[i + 2..end].first() {
+                    Some('/') => {
+                        i += 3;
+                        let directory = Node::Sequence(vec![
+                            star(any_but_separator(meta)),
+                            Node::Tokens(vec![Token::Raw('/', TokenMeta { position: i - 1 })]),
+                        ]);
+                        star(Node::Group(Group::NonCapturing, Box::new(directory)))
+                    }
+                    None => {
+                        i += 2;
+                        star(Node::Class(CharacterClass {
+                            negated: true,
+                            items: vec![],
+                        }))
+                    }
+                    _ => {
+                        i += 2;
+                        star(any_but_separator(meta))
+                    }
+                }
+            }
+            '*' => {
+                i += 1;
+                star(any_but_separator(meta))
+            }
+            '?' => {
+                i += 1;
+                any_but_separator(meta)
+            }
+            '[' => match class(chars, i, end) {
+                Some((body, next)) => {
+                    let mut class = CharacterClass::try_from(&body[..])?;
+                    if class.negated {
+                        class.items.push(ClassItem::Single(Token::Raw('/', meta)));
+                    }
+                    i = next;
+                    Node::Class(class)
+                }
+                None => {
+                    literal.push(Token::Raw('[', meta));
+                    i += 1;
+                    continue;
+                }
+            },
+            '{' => match alternatives(chars, i, end) {
+                Some((alternatives, next)) => {
+                    let too_deep = ParseError::NestedTooDeep {
+                        limit: MAX_DEPTH,
+                        span: meta.into(),
+                    };
+                    if depth >= MAX_DEPTH {
+                        return Err(too_deep);
+                    }
+                    let alternatives = alternatives
+                        .into_iter()
+                        .map(|(start, end)| sequence(chars, start, end, depth + 1))
+                        .collect::<Result<Vec<_>>>()?;
+                    i = next;
+                    let group = Node::Group(
+                        Group::NonCapturing,
+                        Box::new(Node::alternation(alternatives)),
+                    );
+                    if height(&group) >= MAX_DEPTH {
+                        return Err(too_deep);
+                    }
+                    group
+                }
+                None => {
+                    literal.push(Token::Raw('{', meta));
+                    i += 1;
+                    continue;
+                }
+            },
+            c => {
+                literal.push(Token::Raw(c, meta));
+                i += 1;
+                continue;
+            }
+        };
+        if !literal.is_empty() {
+            nodes.push(Node::Tokens(std::mem::take(&mut literal)));
+        }
+        nodes.push(node);
+    }
+    if !literal.is_empty() {
+        nodes.push(Node::Tokens(literal));
+    }
+    Ok(Node::sequence(nodes))
+}
+
+/// Whether the `**` at `i` starts a path segment
+fn starts_segment(chars: &[char], i: usize) -> bool {
+    i == 0 || chars[i - 1] == '/'
+}
+
+/// `[^/]`, for `?`
+fn any_but_separator(meta: TokenMeta) -> Node {
+    Node::Class(CharacterClass {
+        negated: true,
+        items: vec![ClassItem::Single(Token::Raw('/', meta))],
+    })
+}
+
+/// The class starting at `start`, where a leading `!` negates it, and where the glob carries on
+/// after it
+fn class(chars: &[char], start: usize, end: usize) -> Option<(Vec<Token>, usize)> {
+    class_body(chars, start, end, Some('\\'), '!')
+}
+
+/// Where each of the comma-separated alternatives in the braces starting at `start` are, and
+/// where the glob carries on after them, if the braces are closed before `end` and have a comma
+fn alternatives(chars: &[char], start: usize, end: usize) -> Option<(Vec<(usize, usize)>, usize)> {
+    let mut alternatives = vec![];
+    let mut depth = 0;
+    let mut alternative_start = start + 1;
+    let mut i = start + 1;
+    while i < end {
+        match chars[i] {
+            '\\' => i += 1,
+            '[' => {
+                if let Some((_, next)) = class(chars, i, end) {
+                    i = next;
+                    continue;
+                }
+            }
+            '{' => depth += 1,
+            '}' if depth > 0 => depth -= 1,
+            '}' if alternatives.is_empty() => return None,
+            '}' => {
+                alternatives.push((alternative_start, i));
+                return Some((alternatives, i + 1));
+            }
+            ',' if depth == 0 => {
+                alternatives.push((alternative_start, i));
+                alternative_start = i + 1;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod test {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::alphabet::Alphabet;
+    use crate::error::ErrorKind;
+    use crate::parse::Flags;
+    use crate::pattern::Pattern;
+    use crate::syntax::glob::glob;
+    use crate::syntax::Syntax;
+    use crate::tokens::Token::Raw;
+    use crate::tokens::TokenMeta;
+    use crate::tree::class::{CharacterClass, ClassItem};
+    use crate::tree::group::Group;
+    use crate::tree::Node::{Alternation, Class, Group as GroupNode, Sequence, Tokens};
+
+    #[rstest]
+    fn test_tree() {
+        let expected = Sequence(vec![
+            Tokens(vec![Raw('a', TokenMeta { position: 0 })]),
+            Class(CharacterClass {
+                negated: true,
+                items: vec![ClassItem::Single(Raw('/', TokenMeta { position: 1 }))],
+            }),
+            GroupNode(
+                Group::NonCapturing,
+                Box::new(Alternation(vec![
+                    Tokens(vec![Raw('b', TokenMeta { position: 3 })]),
+                    Tokens(vec![Raw('c', TokenMeta { position: 5 })]),
+                ])),
+            ),
+        ]);
+        assert_eq!(expected, glob("a?{b,c}").expect("Failed to read glob"));
+    }
+
+    #[rstest]
+    #[case("*.rs", "main.rs", true)]
+    #[case("*.rs", "src/main.rs", false)]
+    #[case("src/**/*.rs", "src/main.rs", true)]
+    #[case("src/**/*.rs", "src/a/b/main.rs", true)]
+    #[case("**/target", "target", true)]
+    #[case("**/target", "a/b/target", true)]
+    #[case("a/**", "a/b/c", true)]
+    #[case("a**b", "axyb", true)]
+    #[case("a**b", "a/b", false)]
+    #[case("?", "/", false)]
+    #[case("[!a]", "b", true)]
+    #[case("[!a]", "/", false)]
+    #[case("[!]a]", "]", false)]
+    #[case("[[:digit:]]*", "1a", true)]
+    #[case("{*.rs,*.toml}", "Cargo.toml", true)]
+    #[case("{a,b{c,d}}", "bd", true)]
+    #[case("{a,[,}]}", "}", true)]
+    #[case("{a}", "{a}", true)]
+    #[case("x[", "x[", true)]
+    #[case("\\*", "*", true)]
+    #[case("\\*", "a", false)]
+    fn test_glob(#[case] input: &str, #[case] text: &str, #[case] expected: bool) {
+        let flags = Flags {
+            syntax: Syntax::Glob,
+            ..Flags::default()
+        };
+        let expression =
+            Pattern::parse_with(input, &Alphabet::default(), flags).expect("Failed to parse");
+        assert_eq!(expected, expression.is_match(text));
+    }
+
+    #[rstest]
+    fn test_too_deep() {
+        let input = format!("{}b{}", "{a,".repeat(5000), "}".repeat(5000));
+        let error = glob(&input).expect_err("Parsed glob nested too deep");
+        assert_eq!(ErrorKind::NestedTooDeep, error.kind());
+    }
+}
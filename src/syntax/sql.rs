@@ -0,0 +1,291 @@
+use crate::error::{ParseError, Result, Span};
+use crate::syntax::{class_body, star};
+use crate::tokens::{Token, TokenMeta};
+use crate::tree::class::CharacterClass;
+use crate::tree::group::Group;
+use crate::tree::repetition::Repetition;
+use crate::tree::special::Special;
+use crate::tree::{height, Node, MAX_DEPTH};
+
+/// Builds the tree for a `LIKE` pattern, where `%` matches any text, `_` matches any one
+/// character, and everything else, including whatever follows `escape`, matches itself
+pub(crate) fn like(expression: &str, escape: Option<char>) -> Result<Node> {
+    Pattern::new(expression, escape, false).read()
+}
+
+/// Builds the tree for a `SIMILAR TO` pattern, which also has `|`, `(..)`, `[..]`, `*`, `+`, `?`
+/// and `{m,n}` with their regular expression meanings.  Unlike a regular expression, `.` is
+/// literal.
+pub(crate) fn similar_to(expression: &str, escape: Option<char>) -> Result<Node> {
+    Pattern::new(expression, escape, true).read()
+}
+
+/// A pattern being read, one character at a time
+#[derive(Debug)]
+struct Pattern {
+    chars: Vec<char>,
+    position: usize,
+    /// How many groups the pattern is inside at `position`
+    depth: usize,
+    escape: Option<char>,
+    /// Whether this is a `SIMILAR TO` pattern, rather than a `LIKE` one
+    similar: bool,
+}
+
+impl Pattern {
+    fn new(expression: &str, escape: Option<char>, similar: bool) -> Self {
+        Pattern {
+            chars: expression.chars().collect(),
+            position: 0,
+            depth: 0,
+            escape,
+            similar,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn meta(&self) -> TokenMeta {
+        TokenMeta {
+            position: self.position,
+        }
+    }
+
+    fn too_deep(meta: TokenMeta) -> ParseError {
+        ParseError::NestedTooDeep {
+            limit: MAX_DEPTH,
+            span: meta.into(),
+        }
+    }
+
+    fn read(mut self) -> Result<Node> {
+        let node = self.alternation()?;
+        match self.peek() {
+            Some(bracket) => Err(ParseError::UnmatchedBracket {
+                bracket,
+                span: self.meta().into(),
+            }),
+            None => Ok(node),
+        }
+    }
+
+    fn alternation(&mut self) -> Result<Node> {
+        let mut alternatives = vec![self.sequence()?];
+        while self.similar && self.peek() == Some('|') {
+            self.position += 1;
+            alternatives.push(self.sequence()?);
+        }
+        Ok(Node::alternation(alternatives))
+    }
+
+    fn sequence(&mut self) -> Result<Node> {
+        let mut nodes: Vec<Node> = vec![];
+        while let Some(c) = self.peek() {
+            if self.similar && matches!(c, '|' | ')') {
+                break;
+            }
+            let mut node = self.atom(c)?;
+            let meta = self.meta();
+            while let Some(quantifier) = self.quantifier()? {
+                node = Node::Augmented(Box::new(node), Box::new(quantifier));
+                if height(&node) >= MAX_DEPTH {
+                    return Err(Self::too_deep(meta));
+                }
+            }
+            match (nodes.last_mut(), node) {
+                (Some(Node::Tokens(tokens)), Node::Tokens(more)) => tokens.extend(more),
+                (_, node) => nodes.push(node),
+            }
+        }
+        Ok(Node::sequence(nodes))
+    }
+
+    /// Reads the atom that starts with `c`, the next character
+    fn atom(&mut self, c: char) -> Result<Node> {
+        let meta = self.meta();
+        self.position += 1;
+        Ok(match c {
+            c if Some(c) == self.escape => match self.peek() {
+                Some(escaped) => {
+                    self.position += 1;
+                    Node::Tokens(vec![Token::Raw(escaped, meta)])
+                }
+                None => return Err(ParseError::TrailingEscape { span: meta.into() }),
+            },
+            '%' => star(any()),
+            '_' => any(),
+            '(' if self.similar => {
+                if self.depth >= MAX_DEPTH {
+                    return Err(Self::too_deep(meta));
+                }
+                self.depth += 1;
+                let content = self.alternation()?;
+                self.depth -= 1;
+                if height(&content) >= MAX_DEPTH {
+                    return Err(Self::too_deep(meta));
+                }
+                if self.peek() != Some(')') {
+                    return Err(ParseError::UnclosedBracket {
+                        bracket: '(',
+                        span: meta.into(),
+                    });
+                }
+                self.position += 1;
+                Node::Group(Group::NonCapturing, Box::new(content))
+            }
+            '[' if self.similar => {
+                let end = self.chars.len();
+                let (body, next) = class_body(&self.chars, meta.position, end, self.escape, '^')
+                    .ok_or(ParseError::UnclosedBracket {
+                        bracket: '[',
+                        span: meta.into(),
+                    })?;
+                self.position = next;
+                Node::Class(CharacterClass::try_from(&body[..])?)
+            }
+            '*' | '+' | '?' if self.similar => {
+                return Err(ParseError::NothingToRepeat {
+                    quantifier: c,
+                    span: meta.into(),
+                })
+            }
+            c => Node::Tokens(vec![Token::Raw(c, meta)]),
+        })
+    }
+
+    /// Reads the quantifier that comes next, if there is one
+    fn quantifier(&mut self) -> Result<Option<Node>> {
+        if !self.similar {
+            return Ok(None);
+        }
+        let special = match self.peek() {
+            Some('*') => Special::Star,
+            Some('+') => Special::Plus,
+            Some('?') => Special::Question,
+            Some('{') => return self.repetition(),
+            _ => return Ok(None),
+        };
+        self.position += 1;
+        Ok(Some(Node::Special(special)))
+    }
+
+    /// Reads a repetition like `{2,5}`, leaving braces that don't hold one to be literal
+    fn repetition(&mut self) -> Result<Option<Node>> {
+        let start = self.position + 1;
+        let Some(length) = self.chars[start..].iter().position(|&c| c == '}') else {
+            return Ok(None);
+        };
+        let body: Vec<_> = (start..start + length)
+            .map(|position| Token::Raw(self.chars[position], TokenMeta { position }))
+            .collect();
+        let span = Span::new(self.position, start + length + 1);
+        Ok(Repetition::parse(&body, span)?.map(|repetition| {
+            self.position = start + length + 1;
+            Node::Repetition(repetition)
+        }))
+    }
+}
+
+/// Any one character
+fn any() -> Node {
+    Node::Class(CharacterClass {
+        negated: true,
+        items: vec![],
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod test {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::alphabet::Alphabet;
+    use crate::error::ErrorKind;
+    use crate::parse::Flags;
+    use crate::pattern::Pattern;
+    use crate::syntax::Syntax;
+
+    const LIKE: Syntax = Syntax::Like { escape: Some('!') };
+    const SIMILAR_TO: Syntax = Syntax::SimilarTo { escape: Some('!') };
+
+    #[rstest]
+    #[case(LIKE, "a%", "abc", true)]
+    #[case(LIKE, "a%", "ba", false)]
+    #[case(LIKE, "%", "", true)]
+    #[case(LIKE, "a_c", "abc", true)]
+    #[case(LIKE, "a_c", "ac", false)]
+    #[case(LIKE, "100!%", "100%", true)]
+    #[case(LIKE, "100!%", "1000", false)]
+    #[case(LIKE, "a!!", "a!", true)]
+    #[case(LIKE, "(a|b)", "(a|b)", true)]
+    #[case(Syntax::Like { escape: None }, "a!%", "a!bc", true)]
+    #[case(SIMILAR_TO, "(a|b)+c", "abac", true)]
+    #[case(SIMILAR_TO, "[a-c]{2}%", "cab", true)]
+    #[case(SIMILAR_TO, "[^a-c]_", "dz", true)]
+    #[case(SIMILAR_TO, "[^a-c]_", "az", false)]
+    #[case(SIMILAR_TO, "x.y", "x.y", true)]
+    #[case(SIMILAR_TO, "x.y", "xzy", false)]
+    #[case(SIMILAR_TO, "a!*", "a*", true)]
+    #[case(SIMILAR_TO, "a{b", "a{b", true)]
+    #[case(SIMILAR_TO, "%(b|d)%", "abc", true)]
+    fn test_match(
+        #[case] syntax: Syntax,
+        #[case] input: &str,
+        #[case] text: &str,
+        #[case] expected: bool,
+    ) {
+        let flags = Flags {
+            syntax,
+            ..Flags::default()
+        };
+        let expression =
+            Pattern::parse_with(input, &Alphabet::default(), flags).expect("Failed to parse");
+        assert_eq!(expected, expression.is_match(text));
+    }
+
+    #[rstest]
+    #[case(LIKE, "a!", "Escape character at position 1 has nothing to escape")]
+    #[case(SIMILAR_TO, "(a|b", "Unclosed ( at position 0")]
+    #[case(SIMILAR_TO, "a)", "Unmatched ) at position 1")]
+    #[case(SIMILAR_TO, "[ab", "Unclosed [ at position 0")]
+    #[case(SIMILAR_TO, "+a", "Nothing to repeat before + at position 0")]
+    #[case(
+        SIMILAR_TO,
+        "a{3,2}",
+        "Repetition {3,2} at position 1 has its bounds out of order"
+    )]
+    fn test_malformed(#[case] syntax: Syntax, #[case] input: &str, #[case] expected: &str) {
+        let flags = Flags {
+            syntax,
+            ..Flags::default()
+        };
+        let error =
+            Pattern::parse_with(input, &Alphabet::default(), flags).expect_err("Should not parse");
+        assert_eq!(expected, format!("{error}"));
+    }
+
+    #[rstest]
+    #[case(Syntax::Like { escape: None }, Some(LIKE))]
+    #[case(Syntax::SimilarTo { escape: None }, Some(SIMILAR_TO))]
+    #[case(Syntax::Regex, None)]
+    #[case(Syntax::Glob, None)]
+    fn test_with_escape(#[case] syntax: Syntax, #[case] expected: Option<Syntax>) {
+        assert_eq!(expected, syntax.with_escape('!').ok());
+    }
+
+    #[rstest]
+    #[case("(".repeat(100_000))]
+    #[case(format!("a{}", "*".repeat(100_000)))]
+    fn test_too_deep(#[case] input: String) {
+        let flags = Flags {
+            syntax: SIMILAR_TO,
+            ..Flags::default()
+        };
+        let error =
+            Pattern::parse_with(&input, &Alphabet::default(), flags).expect_err("Should not parse");
+        assert_eq!(ErrorKind::NestedTooDeep, error.kind());
+    }
+}
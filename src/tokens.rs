@@ -1,25 +1,79 @@
 use std::fmt::{Display, Formatter, Write};
-use std::iter::Enumerate;
+use std::iter::{Enumerate, Peekable};
 use std::str::Chars;
 
+use thiserror::Error;
+
+use crate::dialect::Dialect;
+use crate::error::ParseError;
+use crate::tree::class::closes_class;
+
+pub(crate) mod verbose;
+
 #[derive(Debug, Eq, PartialEq, Copy, Clone)]
 pub(crate) struct TokenMeta {
     pub(crate) position: usize,
 }
 
-#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Eq, PartialEq, Clone)]
 pub(crate) enum Token {
     Escaped(char, TokenMeta),
     Raw(char, TokenMeta),
     TrailingEscapeCharacter(TokenMeta),
+    /// A Unicode property class like `\p{Greek}`, which is negated if it's `\P`
+    Property(bool, String, TokenMeta),
+    /// A character written as an escape code, like `\n` or `\x41`
+    Encoded(char, TokenMeta),
+    /// An escape code that we couldn't decode
+    Malformed(EscapeError, TokenMeta),
+    /// A reference back to a capture group by number or name, like `\1` or `\k<name>`
+    Backreference(String, TokenMeta),
+}
+
+/// What went wrong when decoding an escape code
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Error)]
+#[non_exhaustive]
+pub enum EscapeError {
+    #[error("expected a digit but found {0:?}")]
+    InvalidDigit(char),
+    #[error("expected more digits")]
+    MissingDigits,
+    #[error("the braces are not closed")]
+    Unclosed,
+    #[error("{0:#X} is not a valid character")]
+    InvalidCodePoint(u32),
+    #[error("expected a letter after \\c")]
+    InvalidControl,
+    #[error("expected a group name in <>, {{}} or '' after \\k")]
+    MissingName,
 }
 
 impl Token {
-    pub(crate) fn to_char(self) -> char {
+    pub(crate) fn to_char(&self) -> char {
         match self {
-            Token::Escaped(c, _) => c,
-            Token::Raw(c, _) => c,
+            Token::Escaped(c, _) => *c,
+            Token::Raw(c, _) => *c,
             Token::TrailingEscapeCharacter(_) => '\\',
+            Token::Property(false, ..) => 'p',
+            Token::Property(true, ..) => 'P',
+            Token::Encoded(c, _) => *c,
+            Token::Malformed(..) => '\\',
+            Token::Backreference(..) => 'k',
+        }
+    }
+
+    /// An error describing why this token can't be used where it was found
+    pub(crate) fn problem(&self) -> ParseError {
+        match self {
+            Token::TrailingEscapeCharacter(m) => ParseError::TrailingEscape { span: (*m).into() },
+            Token::Malformed(e, m) => ParseError::MalformedEscape {
+                error: *e,
+                span: (*m).into(),
+            },
+            _ => ParseError::UnexpectedToken {
+                token: self.to_string(),
+                span: (*self.meta()).into(),
+            },
         }
     }
 
@@ -33,49 +87,271 @@ impl Token {
             Token::Escaped(_, m) => m,
             Token::Raw(_, m) => m,
             Token::TrailingEscapeCharacter(m) => m,
+            Token::Property(_, _, m) => m,
+            Token::Encoded(_, m) => m,
+            Token::Malformed(_, m) => m,
+            Token::Backreference(_, m) => m,
         }
     }
 }
 
 impl Display for Token {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_char(self.to_char())
+        match self {
+            Token::Property(_, name, _) => write!(f, "\\{}{{{name}}}", self.to_char()),
+            Token::Backreference(name, _) if name.starts_with(|c: char| c.is_ascii_digit()) => {
+                write!(f, "\\{name}")
+            }
+            Token::Backreference(name, _) => write!(f, "\\k<{name}>"),
+            _ => f.write_char(self.to_char()),
+        }
     }
 }
 
 #[derive(Debug)]
-pub(crate) struct TokenIterator<'a>(Enumerate<Chars<'a>>);
+pub(crate) struct TokenIterator<'a> {
+    chars: Peekable<Enumerate<Chars<'a>>>,
+    /// The dialect to read escapes and bracket expressions in
+    dialect: Dialect,
+    /// What's been read of the bracket expression we're in, if it's being read literally
+    class: Option<Vec<Token>>,
+}
 
-impl<'a> Iterator for TokenIterator<'a> {
-    type Item = Token;
+impl<'a> TokenIterator<'a> {
+    fn new(chars: Chars<'a>) -> Self {
+        TokenIterator {
+            chars: chars.enumerate().peekable(),
+            dialect: Dialect::default(),
+            class: None,
+        }
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        self.0.next().map(|(position, c)| match c {
+    /// Reads tokens as `dialect` writes them, so in POSIX bracket expressions every character
+    /// stands for itself, including `\`, and in basic expressions `\(` is a group and `(` is literal
+    pub(crate) fn in_dialect(self, dialect: Dialect) -> Self {
+        TokenIterator { dialect, ..self }
+    }
+
+    /// Skips everything up to and including the next newline without decoding any escapes, for the
+    /// rest of a comment
+    pub(crate) fn skip_line(&mut self) {
+        self.chars.find(|(_, c)| *c == '\n');
+    }
+
+    /// Reads the digits of a character code, either exactly `length` of them or any number in
+    /// braces
+    fn code_point(&mut self, radix: u32, length: usize) -> Result<char, EscapeError> {
+        let digits = if let Some((_, '{')) = self.chars.peek() {
+            self.chars.next();
+            let mut digits = vec![];
+            loop {
+                match self.chars.next() {
+                    Some((_, '}')) => break digits,
+                    Some((_, c)) => digits.push(c),
+                    None => return Err(EscapeError::Unclosed),
+                }
+            }
+        } else {
+            let mut digits = vec![];
+            while digits.len() < length {
+                match self.chars.peek() {
+                    Some((_, c)) if c.is_digit(radix) => digits.push(*c),
+                    Some((_, c)) => return Err(EscapeError::InvalidDigit(*c)),
+                    None => return Err(EscapeError::MissingDigits),
+                }
+                self.chars.next();
+            }
+            digits
+        };
+        if digits.is_empty() {
+            return Err(EscapeError::MissingDigits);
+        }
+        let value = digits.iter().try_fold(0u32, |value, c| {
+            let digit = c.to_digit(radix).ok_or(EscapeError::InvalidDigit(*c))?;
+            Ok(value.saturating_mul(radix).saturating_add(digit))
+        })?;
+        char::from_u32(value).ok_or(EscapeError::InvalidCodePoint(value))
+    }
+
+    /// Reads an octal escape that started with `\0`, which has up to two more digits
+    fn octal(&mut self) -> char {
+        let mut value = 0;
+        for _ in 0..2 {
+            match self.chars.peek().and_then(|(_, c)| c.to_digit(8)) {
+                Some(digit) => {
+                    value = value * 8 + digit;
+                    self.chars.next();
+                }
+                None => break,
+            }
+        }
+        char::from_u32(value).unwrap_or_default()
+    }
+
+    fn control(&mut self) -> Result<char, EscapeError> {
+        match self.chars.next() {
+            Some((_, c)) if c.is_ascii_alphabetic() => {
+                Ok(char::from(c.to_ascii_uppercase() as u8 ^ 0x40))
+            }
+            _ => Err(EscapeError::InvalidControl),
+        }
+    }
+
+    /// Reads the rest of a numbered backreference, after its first digit
+    fn reference_number(&mut self, first: char) -> String {
+        let mut number = first.to_string();
+        while let Some((_, c)) = self.chars.peek().filter(|(_, c)| c.is_ascii_digit()) {
+            number.push(*c);
+            self.chars.next();
+        }
+        number
+    }
+
+    /// Reads the name after `\k`, which is in angle brackets, braces or single quotes
+    fn reference_name(&mut self) -> Result<String, EscapeError> {
+        let closing = match self.chars.next() {
+            Some((_, '<')) => '>',
+            Some((_, '{')) => '}',
+            Some((_, '\'')) => '\'',
+            _ => return Err(EscapeError::MissingName),
+        };
+        let mut name = String::new();
+        for (_, c) in self.chars.by_ref() {
+            if c == closing {
+                return Ok(name);
+            }
+            name.push(c);
+        }
+        Err(EscapeError::Unclosed)
+    }
+
+    /// Reads the name after `\p`, which is either a single letter or in braces.
+    ///
+    /// If the braces aren't closed, the name we return will start with the opening brace.
+    fn property_name(&mut self) -> String {
+        match self.chars.next() {
+            Some((_, '{')) => {
+                let mut name = String::new();
+                for (_, c) in self.chars.by_ref() {
+                    if c == '}' {
+                        return name;
+                    }
+                    name.push(c);
+                }
+                format!("{{{name}")
+            }
+            Some((_, c)) => c.to_string(),
+            None => String::new(),
+        }
+    }
+
+    /// Reads the next token as it's written, before any of the dialect's swaps
+    fn read(&mut self) -> Option<Token> {
+        if let Some(body) = &mut self.class {
+            let (position, c) = self.chars.next()?;
+            let token = Token::Raw(c, TokenMeta { position });
+            if c == ']' && closes_class(body) {
+                self.class = None;
+            } else {
+                body.push(token.clone());
+            }
+            return Some(token);
+        }
+        self.chars.next().map(|(position, c)| match c {
             '\\' => {
-                if let Some((_, n)) = self.0.next() {
-                    Token::Escaped(n, TokenMeta { position })
-                } else {
-                    Token::TrailingEscapeCharacter(TokenMeta { position })
+                let meta = TokenMeta { position };
+                let decoded = match self.chars.next() {
+                    Some((_, p @ ('p' | 'P'))) => {
+                        return Token::Property(p == 'P', self.property_name(), meta)
+                    }
+                    Some((_, 'x')) => self.code_point(16, 2),
+                    Some((_, 'u')) => self.code_point(16, 4),
+                    Some((_, 'U')) => self.code_point(16, 8),
+                    Some((_, 'o')) => match self.chars.peek() {
+                        Some((_, '{')) => self.code_point(8, 0),
+                        _ => Err(EscapeError::MissingDigits),
+                    },
+                    Some((_, '0')) => Ok(self.octal()),
+                    Some((_, d @ '1'..='9')) => {
+                        return Token::Backreference(self.reference_number(d), meta)
+                    }
+                    Some((_, 'k')) => match self.reference_name() {
+                        Ok(name) => return Token::Backreference(name, meta),
+                        Err(e) => Err(e),
+                    },
+                    Some((_, 'c')) => self.control(),
+                    Some((_, 'a')) => Ok('\x07'),
+                    Some((_, 'e')) => Ok('\x1B'),
+                    Some((_, 'f')) => Ok('\x0C'),
+                    Some((_, 'n')) => Ok('\n'),
+                    Some((_, 'r')) => Ok('\r'),
+                    Some((_, 't')) => Ok('\t'),
+                    Some((_, 'v')) => Ok('\x0B'),
+                    Some((_, n)) => return Token::Escaped(n, meta),
+                    None => return Token::TrailingEscapeCharacter(meta),
+                };
+                match decoded {
+                    Ok(c) => Token::Encoded(c, meta),
+                    Err(e) => Token::Malformed(e, meta),
                 }
             }
+            '[' if self.dialect.is_posix() => {
+                self.class = Some(vec![]);
+                Token::Raw(c, TokenMeta { position })
+            }
             _ => Token::Raw(c, TokenMeta { position }),
         })
     }
 }
 
+impl<'a> Iterator for TokenIterator<'a> {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let token = self.read()?;
+        Some(self.dialect.translate(token))
+    }
+}
+
+/// The tokens for the digits of a numbered backreference read as an octal escape instead, like
+/// `\101` for `A`. Up to three octal digits make one character, and any digits after them, or an
+/// `8` or `9` at the start, stand for themselves.
+pub(crate) fn octal_digits(number: &str, meta: TokenMeta) -> Vec<Token> {
+    let length = number.chars().take(3).take_while(|c| c.is_digit(8)).count();
+    let mut tokens = vec![];
+    if let Ok(value) = u32::from_str_radix(&number[..length], 8) {
+        tokens.push(Token::Encoded(
+            char::from_u32(value).unwrap_or_default(),
+            meta,
+        ));
+    }
+    for (i, c) in number.chars().enumerate().skip(length) {
+        tokens.push(match i {
+            0 => Token::Escaped(c, meta),
+            _ => Token::Raw(
+                c,
+                TokenMeta {
+                    position: meta.position + 1 + i,
+                },
+            ),
+        });
+    }
+    tokens
+}
+
 pub(crate) trait Tokenise<'a> {
     fn tokenise(self) -> TokenIterator<'a>;
 }
 
 impl<'a> Tokenise<'a> for Chars<'a> {
     fn tokenise(self) -> TokenIterator<'a> {
-        TokenIterator(self.enumerate())
+        TokenIterator::new(self)
     }
 }
 
 impl<'a> Tokenise<'a> for &'a String {
     fn tokenise(self) -> TokenIterator<'a> {
-        TokenIterator(self.chars().enumerate())
+        TokenIterator::new(self.chars())
     }
 }
 
@@ -84,14 +360,62 @@ mod test {
     use pretty_assertions::assert_eq;
     use rstest::rstest;
 
-    use crate::tokens::{Token, TokenMeta, Tokenise};
+    use crate::dialect::Dialect;
+    use crate::tokens::{octal_digits, EscapeError, Token, TokenMeta, Tokenise};
 
     #[rstest]
     #[case("a", vec![Token::Raw('a', TokenMeta {position: 0})])]
     #[case("a\\b", vec![Token::Raw('a', TokenMeta {position: 0}), Token::Escaped('b', TokenMeta {position: 1})])]
     #[case("a\\", vec![Token::Raw('a', TokenMeta {position: 0}), Token::TrailingEscapeCharacter(TokenMeta {position: 1})])]
+    #[case("\\pLa", vec![Token::Property(false, "L".to_string(), TokenMeta {position: 0}), Token::Raw('a', TokenMeta {position: 3})])]
+    #[case("\\P{Greek}a", vec![Token::Property(true, "Greek".to_string(), TokenMeta {position: 0}), Token::Raw('a', TokenMeta {position: 9})])]
+    #[case("\\p{Greek", vec![Token::Property(false, "{Greek".to_string(), TokenMeta {position: 0})])]
+    #[case("\\p", vec![Token::Property(false, "".to_string(), TokenMeta {position: 0})])]
+    #[case("\\n\\t", vec![Token::Encoded('\n', TokenMeta {position: 0}), Token::Encoded('\t', TokenMeta {position: 2})])]
+    #[case("\\x41b", vec![Token::Encoded('A', TokenMeta {position: 0}), Token::Raw('b', TokenMeta {position: 4})])]
+    #[case("\\x{1F600}", vec![Token::Encoded('😀', TokenMeta {position: 0})])]
+    #[case("\\u{1F600}", vec![Token::Encoded('😀', TokenMeta {position: 0})])]
+    #[case("\\u00e9", vec![Token::Encoded('é', TokenMeta {position: 0})])]
+    #[case("\\U0001F600", vec![Token::Encoded('😀', TokenMeta {position: 0})])]
+    #[case("\\0", vec![Token::Encoded('\0', TokenMeta {position: 0})])]
+    #[case("\\0128", vec![Token::Encoded('\n', TokenMeta {position: 0}), Token::Raw('8', TokenMeta {position: 4})])]
+    #[case("\\o{101}", vec![Token::Encoded('A', TokenMeta {position: 0})])]
+    #[case("\\cJ", vec![Token::Encoded('\n', TokenMeta {position: 0})])]
+    #[case("a\\xZ", vec![Token::Raw('a', TokenMeta {position: 0}), Token::Malformed(EscapeError::InvalidDigit('Z'), TokenMeta {position: 1}), Token::Raw('Z', TokenMeta {position: 3})])]
+    #[case("\\x4", vec![Token::Malformed(EscapeError::MissingDigits, TokenMeta {position: 0})])]
+    #[case("\\x{}", vec![Token::Malformed(EscapeError::MissingDigits, TokenMeta {position: 0})])]
+    #[case("\\x{41", vec![Token::Malformed(EscapeError::Unclosed, TokenMeta {position: 0})])]
+    #[case("\\u{D800}", vec![Token::Malformed(EscapeError::InvalidCodePoint(0xD800), TokenMeta {position: 0})])]
+    #[case("\\x{4G}", vec![Token::Malformed(EscapeError::InvalidDigit('G'), TokenMeta {position: 0})])]
+    #[case("\\o12", vec![Token::Malformed(EscapeError::MissingDigits, TokenMeta {position: 0}), Token::Raw('1', TokenMeta {position: 2}), Token::Raw('2', TokenMeta {position: 3})])]
+    #[case("\\c1", vec![Token::Malformed(EscapeError::InvalidControl, TokenMeta {position: 0})])]
+    #[case("\\12a", vec![Token::Backreference("12".to_string(), TokenMeta {position: 0}), Token::Raw('a', TokenMeta {position: 3})])]
+    #[case("\\k<name>\\k{x}\\k'y'", vec![Token::Backreference("name".to_string(), TokenMeta {position: 0}), Token::Backreference("x".to_string(), TokenMeta {position: 8}), Token::Backreference("y".to_string(), TokenMeta {position: 13})])]
+    #[case("\\kx", vec![Token::Malformed(EscapeError::MissingName, TokenMeta {position: 0})])]
+    #[case("\\k<x", vec![Token::Malformed(EscapeError::Unclosed, TokenMeta {position: 0})])]
     fn tokenise(#[case] input: String, #[case] expected: Vec<Token>) {
         let actual: Vec<_> = input.tokenise().collect();
         assert_eq!(expected, actual);
     }
+
+    #[rstest]
+    #[case("[\\n]\\n", vec![Token::Raw('[', TokenMeta {position: 0}), Token::Raw('\\', TokenMeta {position: 1}), Token::Raw('n', TokenMeta {position: 2}), Token::Raw(']', TokenMeta {position: 3}), Token::Encoded('\n', TokenMeta {position: 4})])]
+    #[case("[]\\]", vec![Token::Raw('[', TokenMeta {position: 0}), Token::Raw(']', TokenMeta {position: 1}), Token::Raw('\\', TokenMeta {position: 2}), Token::Raw(']', TokenMeta {position: 3})])]
+    #[case("[[:a:]]\\.", vec![Token::Raw('[', TokenMeta {position: 0}), Token::Raw('[', TokenMeta {position: 1}), Token::Raw(':', TokenMeta {position: 2}), Token::Raw('a', TokenMeta {position: 3}), Token::Raw(':', TokenMeta {position: 4}), Token::Raw(']', TokenMeta {position: 5}), Token::Raw(']', TokenMeta {position: 6}), Token::Escaped('.', TokenMeta {position: 7})])]
+    fn tokenise_literal_brackets(#[case] input: String, #[case] expected: Vec<Token>) {
+        let actual: Vec<_> = input
+            .tokenise()
+            .in_dialect(Dialect::PosixExtended)
+            .collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[rstest]
+    #[case("101", vec![Token::Encoded('A', TokenMeta {position: 0})])]
+    #[case("1012", vec![Token::Encoded('A', TokenMeta {position: 0}), Token::Raw('2', TokenMeta {position: 4})])]
+    #[case("18", vec![Token::Encoded('\x01', TokenMeta {position: 0}), Token::Raw('8', TokenMeta {position: 2})])]
+    #[case("9", vec![Token::Escaped('9', TokenMeta {position: 0})])]
+    fn octal_digit_tokens(#[case] number: &str, #[case] expected: Vec<Token>) {
+        assert_eq!(expected, octal_digits(number, TokenMeta { position: 0 }));
+    }
 }
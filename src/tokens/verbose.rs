@@ -0,0 +1,143 @@
+use crate::tokens::{Token, TokenIterator};
+use crate::tree::class::closes_class;
+
+/// Drops the whitespace and `#` comments from tokens in extended mode, which is on from the start
+/// if `extended` is set and otherwise switched on and off by flag groups like `(?x)` and `(?-x:`.
+///
+/// Whitespace in a class, like `[ ]`, and escaped whitespace, like `\ `, are kept. Comments are
+/// skipped before their escapes are decoded, so `# \u{` doesn't run on to the next `}`.
+#[derive(Debug)]
+pub(crate) struct Verbose<'a> {
+    tokens: TokenIterator<'a>,
+    /// Whether extended mode is on in each enclosing group, innermost last
+    groups: Vec<bool>,
+    /// What's been read of a class so far, if we're in one
+    class: Option<Vec<Token>>,
+    /// The flag letters after `(?`, if we're reading them
+    header: Option<String>,
+    /// Whether the last token was an opening parenthesis
+    opened: bool,
+}
+
+impl<'a> Verbose<'a> {
+    pub(crate) fn new(tokens: TokenIterator<'a>, extended: bool) -> Self {
+        Verbose {
+            tokens,
+            groups: vec![extended],
+            class: None,
+            header: None,
+            opened: false,
+        }
+    }
+
+    fn extended(&self) -> bool {
+        self.groups.last().copied().unwrap_or_default()
+    }
+
+    /// Keeps track of classes and groups, returning whether the token should be dropped
+    fn skip(&mut self, token: &Token) -> bool {
+        let opened = std::mem::take(&mut self.opened);
+        if let Some(body) = &mut self.class {
+            if matches!(token, Token::Raw(']', _)) && closes_class(body) {
+                self.class = None;
+            } else {
+                body.push(token.clone());
+            }
+            return false;
+        }
+        if let Some(header) = &mut self.header {
+            match token {
+                Token::Raw(c @ ('-' | 'a'..='z'), _) => {
+                    header.push(*c);
+                    return false;
+                }
+                Token::Raw(end @ (':' | ')'), _) => {
+                    let header = self.header.take().unwrap_or_default();
+                    if *end == ')' {
+                        // The flags apply to the rest of the enclosing group
+                        self.groups.pop();
+                    }
+                    if let Some(extended) = self.groups.last_mut() {
+                        *extended = switch(&header, *extended);
+                    }
+                    return false;
+                }
+                _ => self.header = None,
+            }
+        }
+        match token {
+            Token::Raw('?', _) if opened => self.header = Some(String::new()),
+            Token::Raw('(', _) => {
+                self.groups.push(self.extended());
+                self.opened = true;
+            }
+            Token::Raw(')', _) if self.groups.len() > 1 => {
+                self.groups.pop();
+            }
+            Token::Raw('[', _) => self.class = Some(vec![]),
+            Token::Raw(c, _) if c.is_whitespace() => return self.extended(),
+            _ => {}
+        }
+        false
+    }
+}
+
+/// Whether extended mode is on after flags like `i-x`
+fn switch(flags: &str, extended: bool) -> bool {
+    let (on, off) = flags.split_once('-').unwrap_or((flags, ""));
+    if off.contains('x') {
+        false
+    } else {
+        extended || on.contains('x')
+    }
+}
+
+impl<'a> Iterator for Verbose<'a> {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Token> {
+        loop {
+            let token = self.tokens.next()?;
+            if self.class.is_none() && self.header.is_none() && self.extended() {
+                if let Token::Raw('#', _) = token {
+                    // A comment runs to the end of the line
+                    self.tokens.skip_line();
+                    continue;
+                }
+            }
+            if !self.skip(&token) {
+                return Some(token);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::tokens::verbose::Verbose;
+    use crate::tokens::Tokenise;
+
+    #[rstest]
+    #[case("a b", false, "a b")]
+    #[case("a b\t\n", true, "ab")]
+    #[case("a # comment\nb", true, "ab")]
+    #[case("a#b", false, "a#b")]
+    #[case("[ #]\\ ", true, "[ #] ")]
+    #[case("[] ] ", true, "[] ]")]
+    #[case("a (?x) b", false, "a (?x)b")]
+    #[case("(?x: a ) b", false, "(?x:a) b")]
+    #[case("(?x)(?-x: a ) b", false, "(?x)(?-x: a )b")]
+    #[case("( (?x) a ) b", false, "( (?x)a) b")]
+    #[case("(?ix) a", false, "(?ix)a")]
+    #[case("a # \\u{ oops\nb", true, "ab")]
+    #[case("(?x)a # [\\p{ \nb", false, "(?x)ab")]
+    fn test_verbose(#[case] input: String, #[case] extended: bool, #[case] expected: &str) {
+        let actual: String = Verbose::new(input.tokenise(), extended)
+            .map(|t| t.to_char())
+            .collect();
+        assert_eq!(expected, actual);
+    }
+}
@@ -1,332 +1,537 @@
-use std::mem::take;
+use std::iter::Peekable;
 
-use anyhow::{anyhow, Result};
-
-use crate::tokens::Token;
-use crate::tree::brackets::Bracket;
+use crate::dialect::{Dialect, Feature};
+use crate::error::{ParseError, Result, Span};
+use crate::tokens::{octal_digits, Token, TokenMeta};
+use crate::tree::assertion::Assertion;
+use crate::tree::class::{closes_class, CharacterClass, ClassItem};
 use crate::tree::classify::{Classify, TokenClass};
+use crate::tree::group::Group;
+use crate::tree::reference::Reference;
+use crate::tree::repetition::Repetition;
 use crate::tree::special::Special;
 
-pub(crate) mod brackets;
+pub(crate) mod assertion;
+pub(crate) mod class;
 mod classify;
+pub(crate) mod group;
+pub(crate) mod reference;
+pub(crate) mod repetition;
 pub(crate) mod special;
 
+/// How many levels of nodes the tree can have before we give up on the expression, rather than
+/// running out of stack
+pub(crate) const MAX_DEPTH: usize = 200;
+
 #[derive(Debug, Clone, Eq, PartialEq, Default)]
 pub(crate) enum Node {
     #[default]
     Empty,
     Sequence(Vec<Node>),
     Tokens(Vec<Token>),
-    Bracketed(Bracket, Box<Node>),
+    /// `(...)`, which captures what its content matches under the next group number
+    Capture(Box<Node>),
+    Group(Group, Box<Node>),
     Special(Special),
+    Assertion(Assertion, TokenMeta),
+    Class(CharacterClass),
+    Repetition(Repetition),
     Augmented(Box<Node>, Box<Node>),
-    Alternate(Box<Node>, Box<Node>),
+    /// A quantifier followed by `?`, which prefers fewer repetitions
+    Lazy(Box<Node>, Box<Node>, TokenMeta),
+    /// A quantifier followed by `+`, which never gives back what it has matched
+    Possessive(Box<Node>, Box<Node>, TokenMeta),
+    /// Two or more alternatives separated by `|`, any one of which can match
+    Alternation(Vec<Node>),
+    /// `\1`, `\k<name>` or `(?P=name)`, which matches the text its group captured again
+    Backreference(Reference, TokenMeta),
 }
 
-#[derive(Debug, Clone, Default)]
-enum PartialNode {
-    #[default]
-    Empty,
-    Sequence(Vec<Node>),
-    Tokens(Vec<Token>),
-    Bracketed(Bracket),
-    Alternate(Box<Node>),
-}
+impl Node {
+    /// The nodes one after another, without a sequence if there are fewer than two of them
+    pub(crate) fn sequence(mut nodes: Vec<Node>) -> Node {
+        match nodes.len() {
+            0 => Node::Empty,
+            1 => nodes.remove(0),
+            _ => Node::Sequence(nodes),
+        }
+    }
+
+    /// Any one of the alternatives, without an alternation if there are fewer than two of them
+    pub(crate) fn alternation(mut alternatives: Vec<Node>) -> Node {
+        match alternatives.len() {
+            0 => Node::Empty,
+            1 => alternatives.remove(0),
+            _ => Node::Alternation(alternatives),
+        }
+    }
 
-impl PartialNode {
-    fn end_with(self, terminator: Node) -> Result<Node> {
+    /// The nodes directly inside this one
+    pub(crate) fn children(&self) -> Vec<&Node> {
         match self {
-            PartialNode::Empty => Ok(terminator),
-            PartialNode::Sequence(mut sequence) => {
-                if let Node::Sequence(_) = terminator {
-                    Err(anyhow!("Can't nest sequences"))
-                } else {
-                    if terminator != Node::Empty {
-                        sequence.push(terminator);
-                    }
-                    if let Some((first, rest)) = sequence.split_first_mut() {
-                        if rest.is_empty() {
-                            Ok(take(first))
-                        } else {
-                            Ok(Node::Sequence(sequence))
-                        }
-                    } else {
-                        Ok(Node::Empty)
+            Node::Sequence(nodes) | Node::Alternation(nodes) => nodes.iter().collect(),
+            Node::Capture(content) | Node::Group(_, content) => vec![content],
+            Node::Augmented(content, quantifier)
+            | Node::Lazy(content, quantifier, _)
+            | Node::Possessive(content, quantifier, _) => vec![content, quantifier],
+            Node::Empty
+            | Node::Tokens(_)
+            | Node::Special(_)
+            | Node::Assertion(..)
+            | Node::Class(_)
+            | Node::Repetition(_)
+            | Node::Backreference(..) => vec![],
+        }
+    }
+}
+
+pub(crate) fn tree<T: IntoIterator<Item = Token>>(tokens: T) -> Result<Node> {
+    tree_in(tokens, Dialect::Pcre)
+}
+
+/// Builds the tree, rejecting anything that `dialect` doesn't support
+pub(crate) fn tree_in<T: IntoIterator<Item = Token>>(tokens: T, dialect: Dialect) -> Result<Node> {
+    let tokens: Vec<_> = tokens.into_iter().collect();
+    let parser = |groups| Parser {
+        tokens: tokens.clone().into_iter().peekable(),
+        dialect,
+        depth: 0,
+        groups,
+        opened: 0,
+        referenced: 0,
+    };
+    let mut first = parser(None);
+    let tree = first.alternation()?;
+    if first.referenced <= first.opened {
+        return Ok(tree);
+    }
+    // Now that we know how many groups there are, `\12` and up beyond them are octal escapes
+    parser(Some(first.opened)).alternation()
+}
+
+/// Reads tokens into a tree by recursive descent, with a method for each level of precedence:
+/// alternation binds loosest, then sequence, then quantifiers, which apply to a single atom
+#[derive(Debug)]
+struct Parser<I: Iterator<Item = Token>> {
+    tokens: Peekable<I>,
+    dialect: Dialect,
+    /// How many groups we're inside
+    depth: usize,
+    /// How many capture groups the whole expression has, once we know, so that a numbered
+    /// backreference to a group past them can be read as an octal escape
+    groups: Option<usize>,
+    /// How many capture groups we've read so far
+    opened: usize,
+    /// The highest group number that a backreference read so far refers to
+    referenced: usize,
+}
+
+impl<I: Iterator<Item = Token>> Parser<I> {
+    /// Reads alternatives separated by `|`, up to the end of the enclosing group
+    fn alternation(&mut self) -> Result<Node> {
+        let mut alternatives = vec![self.sequence()?];
+        while self
+            .tokens
+            .next_if(|t| matches!(t, Token::Raw('|', _)))
+            .is_some()
+        {
+            alternatives.push(self.sequence()?);
+        }
+        Ok(Node::alternation(alternatives))
+    }
+
+    /// Reads nodes one after another, up to the next `|` or the end of the enclosing group
+    fn sequence(&mut self) -> Result<Node> {
+        let mut nodes = vec![];
+        let in_group = self.depth > 0;
+        while let Some(token) = self.tokens.next_if(|t| match t {
+            Token::Raw('|', _) => false,
+            Token::Raw(')', _) => !in_group,
+            _ => true,
+        }) {
+            self.item(&mut nodes, token)?;
+        }
+        Ok(Node::sequence(nodes))
+    }
+
+    /// Adds what `token` starts to the nodes of a sequence, or applies it to the last of them if
+    /// it's a quantifier
+    fn item(&mut self, nodes: &mut Vec<Node>, token: Token) -> Result<()> {
+        trace!("Token: {token:?}, depth: {}", self.depth);
+        let meta = TokenMeta {
+            position: token.position(),
+        };
+        if self.literal_anchor(nodes, &token) {
+            literal(nodes, token);
+            return Ok(());
+        }
+        if let Token::Backreference(number, meta) = &token {
+            if let Some(reference) = group_number(number) {
+                // `\1` to `\9` are always backreferences, like in PCRE
+                if number.len() > 1 && self.groups.is_some_and(|groups| reference > groups) {
+                    for token in octal_digits(number, *meta) {
+                        literal(nodes, token);
                     }
+                    return Ok(());
                 }
+                self.referenced = self.referenced.max(reference);
+            }
+        }
+        match (token.classify(), &token) {
+            (TokenClass::Regular, _) | (TokenClass::CloseBracket, Token::Raw('}', _)) => {
+                literal(nodes, token)
             }
-            PartialNode::Tokens(t) => {
-                if Node::Empty == terminator {
-                    Ok(Node::Tokens(t))
+            (TokenClass::Atom, _) => nodes.push(atom(token, self.dialect)?),
+            (TokenClass::Augmentation, _) => self.quantifier(nodes, token)?,
+            (TokenClass::OpenBracket, Token::Raw('(', _)) => nodes.push(self.group(meta)?),
+            (TokenClass::OpenBracket, Token::Raw('[', _)) => nodes.push(self.class(meta)?),
+            (TokenClass::OpenBracket, _) => self.braces(nodes, token)?,
+            (TokenClass::CloseBracket, _) => {
+                let close = token.to_char();
+                return Err(if self.depth > 0 && close != ')' {
+                    ParseError::MismatchedBrackets {
+                        open: '(',
+                        close,
+                        span: meta.into(),
+                    }
                 } else {
-                    Err(anyhow!("Can't nest {terminator:?} inside a Tokens"))
-                }
+                    ParseError::UnmatchedBracket {
+                        bracket: close,
+                        span: meta.into(),
+                    }
+                });
             }
-            PartialNode::Bracketed(b) => Err(anyhow!("Found an unclosed bracket: {b:?}")),
-            PartialNode::Alternate(first) => Ok(Node::Alternate(first, Box::new(terminator))),
+            (TokenClass::Alternation | TokenClass::Problematic, _) => return Err(token.problem()),
         }
+        Ok(())
     }
-}
 
-trait AppendChild {
-    fn append_child(&mut self, new: Node) -> Result<()>;
-}
+    /// Whether `token` is a BRE `^` that isn't at the start of its sequence or a `$` that isn't at
+    /// the end, which stand for themselves rather than being anchors
+    fn literal_anchor(&mut self, nodes: &[Node], token: &Token) -> bool {
+        if self.dialect != Dialect::PosixBasic {
+            return false;
+        }
+        match token {
+            Token::Raw('^', _) => !nodes.is_empty(),
+            Token::Raw('$', _) => {
+                !matches!(self.tokens.peek(), None | Some(Token::Raw('|' | ')', _)))
+            }
+            _ => false,
+        }
+    }
 
-impl AppendChild for Vec<PartialNode> {
-    fn append_child(&mut self, new: Node) -> Result<()> {
-        trace!("Append Child, stack: {self:?}, child: {new:?}");
-        if let Some(mut containing_partial) = self.pop() {
-            match &mut containing_partial {
-                PartialNode::Empty => self.push(PartialNode::Sequence(vec![new])),
-                PartialNode::Sequence(sequence) => {
-                    sequence.push(new);
-                    self.push(containing_partial);
-                }
-                PartialNode::Tokens(tokens) => {
-                    self.push(PartialNode::Sequence(vec![Node::Tokens(take(tokens)), new]))
-                }
-                PartialNode::Bracketed(_) => {
-                    self.push(containing_partial);
-                    self.push(PartialNode::Sequence(vec![new]));
+    /// Applies `*`, `+` or `?` to the last node, or makes the quantifier before it lazy or
+    /// possessive
+    fn quantifier(&mut self, nodes: &mut Vec<Node>, token: Token) -> Result<()> {
+        let meta = TokenMeta {
+            position: token.position(),
+        };
+        match (nodes.pop(), &token) {
+            (Some(Node::Augmented(content, quantifier)), Token::Raw('?', _)) => {
+                self.dialect.check(Feature::LazyQuantifier, meta)?;
+                nodes.push(Node::Lazy(content, quantifier, meta));
+            }
+            (Some(Node::Augmented(content, quantifier)), Token::Raw('+', _)) => {
+                self.dialect.check(Feature::PossessiveQuantifier, meta)?;
+                nodes.push(Node::Possessive(content, quantifier, meta));
+            }
+            (previous, _) => {
+                nodes.extend(previous);
+                // A BRE `*` with nothing before it to repeat, even if it's just after `^`, is literal
+                let leading = matches!(nodes[..], [] | [Node::Assertion(Assertion::Caret, _)]);
+                if self.dialect == Dialect::PosixBasic
+                    && leading
+                    && matches!(token, Token::Raw('*', _))
+                {
+                    literal(nodes, token);
+                    return Ok(());
                 }
-                PartialNode::Alternate(first) => {
-                    self.append_child(Node::Alternate(take(first), Box::new(new)))?;
+                if !self.augment(nodes, Node::Special((&token).try_into()?), meta)? {
+                    return Err(ParseError::NothingToRepeat {
+                        quantifier: token.to_char(),
+                        span: meta.into(),
+                    });
                 }
             }
-        } else {
-            self.push(PartialNode::Sequence(vec![new]))
         }
-
-        trace!("Appended Child, stack: {self:?}");
         Ok(())
     }
-}
 
-fn close_bracket(
-    stack: Vec<PartialNode>,
-    closing_bracket: Bracket,
-    last_node: Node,
-) -> Result<Vec<PartialNode>> {
-    trace!("Stack: {stack:?}, closing_bracket: {closing_bracket:?}, last_node: {last_node:?}");
-    let (stack, remaining_node) = stack.into_iter().try_rfold(
-        (None, last_node),
-        |(maybe_stack, last_node): (Option<Vec<PartialNode>>, Node), partial| -> Result<_> {
-            trace!("Stack: {maybe_stack:?}, Last Node: {last_node:?}");
-            if let Some(mut stack) = maybe_stack {
-                stack.insert(0, partial);
-                if last_node != Node::Empty {
-                    stack.append_child(last_node)?
-                }
-                Ok((Some(stack), Node::Empty))
-            } else if let PartialNode::Bracketed(opening_bracket) = partial {
-                if opening_bracket == closing_bracket {
-                    Ok((
-                        Some(vec![]),
-                        Node::Bracketed(opening_bracket, Box::new(last_node)),
-                    ))
-                } else {
-                    Err(anyhow!(
-                        "Mismatched brackets: {opening_bracket:?} closed by {closing_bracket:?}"
-                    ))
+    /// Reads what follows `{`, which is a repetition if it's a count up to `}` and there's
+    /// something before it to repeat, and literal text otherwise
+    fn braces(&mut self, nodes: &mut Vec<Node>, open: Token) -> Result<()> {
+        let mut body = vec![];
+        while let Some(token) = self
+            .tokens
+            .next_if(|t| matches!(t, Token::Raw(c, _) if c.is_ascii_digit() || *c == ','))
+        {
+            body.push(token);
+        }
+        if let Some(close) = self.tokens.next_if(|t| matches!(t, Token::Raw('}', _))) {
+            let span = Span::new(open.position(), close.position() + 1);
+            if let Some(repetition) = Repetition::parse(&body, span)? {
+                let meta = TokenMeta {
+                    position: open.position(),
+                };
+                if self.augment(nodes, Node::Repetition(repetition), meta)? {
+                    return Ok(());
                 }
-            } else {
-                let next_node = partial.end_with(last_node)?;
-                Ok((None, next_node))
             }
-        },
-    )?;
-    let stack = stack.ok_or(anyhow!("Close Bracket without Opening Bracket"))?;
+            body.push(close);
+        }
+        for token in [open].into_iter().chain(body) {
+            literal(nodes, token);
+        }
+        Ok(())
+    }
 
-    trace!("Stack: {stack:?} Remaining Node: {remaining_node:?}");
-    if remaining_node == Node::Empty {
-        Ok(stack)
-    } else {
-        assert!(stack.is_empty());
-        Ok(vec![PartialNode::Sequence(vec![remaining_node])])
+    /// Applies a quantifier at `meta` to the last node, or just the last character of literal
+    /// text, returning `false` if there's nothing to apply it to
+    fn augment(&self, nodes: &mut Vec<Node>, quantifier: Node, meta: TokenMeta) -> Result<bool> {
+        let content = match nodes.pop() {
+            Some(Node::Tokens(mut tokens)) if tokens.len() > 1 => {
+                let last = tokens.split_off(tokens.len() - 1);
+                nodes.push(Node::Tokens(tokens));
+                Node::Tokens(last)
+            }
+            Some(node) => node,
+            None => return Ok(false),
+        };
+        if height(&content) >= MAX_DEPTH {
+            return Err(ParseError::NestedTooDeep {
+                limit: MAX_DEPTH,
+                span: meta.into(),
+            });
+        }
+        nodes.push(Node::Augmented(Box::new(content), Box::new(quantifier)));
+        Ok(true)
     }
-}
 
-pub(crate) fn tree<T: IntoIterator<Item = Token>>(tokens: T) -> Result<Node> {
-    let stack: Vec<PartialNode> = tokens.into_iter().try_fold(
-        vec![PartialNode::Empty],
-        |mut stack: Vec<PartialNode>, token: Token| -> Result<Vec<PartialNode>> {
-            trace!("Token: {token:?}, Stack: {stack:?}");
-            let token_class = token.classify();
-            if let Some(node) = stack.pop() {
-                match node {
-                    PartialNode::Empty => match token_class {
-                        TokenClass::OpenBracket => {
-                            stack.push(PartialNode::Bracketed(Bracket::try_from(token)?));
-                            stack.push(PartialNode::Empty);
-                            Ok(stack)
-                        }
-                        TokenClass::CloseBracket => {
-                            close_bracket(stack, token.try_into()?, Node::Empty)
-                        }
-                        TokenClass::Augmentation => {
-                            stack.push(PartialNode::Sequence(vec![Node::Special(
-                                token.try_into()?,
-                            )]));
-                            Ok(stack)
-                        }
-                        TokenClass::Regular => {
-                            stack.push(PartialNode::Tokens(vec![token]));
-                            Ok(stack)
-                        }
-                        TokenClass::Problematic => Err(anyhow!("Unexpected escape")),
-                        TokenClass::Alternation => {
-                            stack.push(PartialNode::Alternate(Box::new(Node::Empty)));
-                            stack.push(PartialNode::Empty);
-                            Ok(stack)
-                        }
-                    },
-                    PartialNode::Sequence(mut sequence) => match token_class {
-                        TokenClass::OpenBracket => {
-                            stack.push(PartialNode::Sequence(sequence));
-                            stack.push(PartialNode::Bracketed(token.try_into()?));
-                            stack.push(PartialNode::Empty);
-                            Ok(stack)
-                        }
-                        TokenClass::CloseBracket => close_bracket(
-                            stack,
-                            token.try_into()?,
-                            PartialNode::Sequence(sequence).end_with(Node::Empty)?,
-                        ),
-                        TokenClass::Augmentation => {
-                            let previous = sequence
-                                .pop()
-                                .ok_or(anyhow!("Special character at start of sequence"))?;
-                            sequence.push(Node::Augmented(
-                                Box::new(previous),
-                                Box::new(Node::Special(token.try_into()?)),
-                            ));
-                            stack.push(PartialNode::Sequence(sequence));
-                            Ok(stack)
-                        }
-                        TokenClass::Regular => {
-                            stack.push(PartialNode::Sequence(sequence));
-                            stack.push(PartialNode::Tokens(vec![token]));
-                            Ok(stack)
-                        }
-                        TokenClass::Problematic => Err(anyhow!("Unexpected Escape")),
-                        TokenClass::Alternation => {
-                            stack.push(PartialNode::Alternate(Box::new(Node::Sequence(sequence))));
-                            stack.push(PartialNode::Empty);
-                            Ok(stack)
-                        }
-                    },
-                    PartialNode::Tokens(mut token_sequence) => match token_class {
-                        TokenClass::OpenBracket => {
-                            let container = stack.pop().ok_or(anyhow!("Stack Underflow"))?;
-                            match container {
-                                PartialNode::Empty => {
-                                    return Err(anyhow!("Found an unexpected Empty on the stack"))
-                                }
-                                PartialNode::Sequence(mut sequence) => {
-                                    sequence.push(Node::Tokens(token_sequence));
-                                    stack.push(PartialNode::Sequence(sequence))
-                                }
-                                PartialNode::Tokens(_) => {
-                                    return Err(anyhow!("Unexpected nested token sequences"))
-                                }
-                                PartialNode::Bracketed(_) | PartialNode::Alternate(_) => {
-                                    stack.push(container);
-                                    stack.push(PartialNode::Sequence(vec![Node::Tokens(
-                                        token_sequence,
-                                    )]));
-                                }
-                            }
-                            stack.push(PartialNode::Bracketed(token.try_into()?));
-                            stack.push(PartialNode::Empty);
-                            Ok(stack)
-                        }
-                        TokenClass::CloseBracket => {
-                            close_bracket(stack, token.try_into()?, Node::Tokens(token_sequence))
-                        }
-                        TokenClass::Augmentation => {
-                            if let Some(augmentee) = token_sequence.pop() {
-                                stack.append_child(Node::Tokens(token_sequence))?;
-                                stack.append_child(Node::Augmented(
-                                    Box::new(Node::Tokens(vec![augmentee])),
-                                    Box::new(Node::Special(token.try_into()?)),
-                                ))?;
-                            } else {
-                                stack.append_child(Node::Special(token.try_into()?))?;
-                            }
-                            Ok(stack)
-                        }
-                        TokenClass::Regular => {
-                            token_sequence.push(token);
-                            stack.push(PartialNode::Tokens(token_sequence));
-                            Ok(stack)
-                        }
-                        TokenClass::Problematic => Err(anyhow!("Unexpected Escape")),
-                        TokenClass::Alternation => {
-                            stack.push(PartialNode::Alternate(Box::new(Node::Tokens(
-                                token_sequence,
-                            ))));
-                            stack.push(PartialNode::Empty);
-                            Ok(stack)
-                        }
-                    },
-                    PartialNode::Bracketed(_) | PartialNode::Alternate(_) => {
-                        Err(anyhow!("Should not find {node:?} at the top of the stack"))
-                    }
+    /// Reads a parenthesised group, after its opening parenthesis
+    fn group(&mut self, open: TokenMeta) -> Result<Node> {
+        let Some(question) = self.tokens.next_if(|t| matches!(t, Token::Raw('?', _))) else {
+            self.opened += 1;
+            let content = self.enclosed(open)?;
+            return Ok(Node::Capture(Box::new(content)));
+        };
+        // A `?` straight after an opening parenthesis starts a group header like `(?:`
+        let mut header = vec![];
+        let group = loop {
+            let Some(token) = self.tokens.next() else {
+                return Err(ParseError::UnclosedBracket {
+                    bracket: '(',
+                    span: Span::new(question.position().saturating_sub(1), question.position()),
+                });
+            };
+            header.push(token);
+            if let Some(group) = Group::parse(&question, &header)? {
+                break group;
+            }
+        };
+        let meta = TokenMeta {
+            position: question.position().saturating_sub(1),
+        };
+        self.dialect.check_group(&group, &header, meta)?;
+        if let Group::Named(..) = group {
+            self.opened += 1;
+        }
+        match group {
+            // Flags that apply to the rest of the enclosing group don't contain anything
+            Group::Flags(_) => Ok(Node::Group(group, Box::new(Node::Empty))),
+            Group::Reference(name) => Ok(Node::Backreference(Reference::Name(name), meta)),
+            group => Ok(Node::Group(group, Box::new(self.enclosed(meta)?))),
+        }
+    }
+
+    /// Reads the contents of a group and its closing parenthesis
+    fn enclosed(&mut self, open: TokenMeta) -> Result<Node> {
+        let too_deep = ParseError::NestedTooDeep {
+            limit: MAX_DEPTH,
+            span: open.into(),
+        };
+        if self.depth >= MAX_DEPTH {
+            return Err(too_deep);
+        }
+        self.depth += 1;
+        let content = self.alternation();
+        self.depth -= 1;
+        match (content, self.tokens.next()) {
+            (Err(e), _) => Err(e),
+            (Ok(content), _) if height(&content) >= MAX_DEPTH => Err(too_deep),
+            (Ok(content), Some(Token::Raw(')', _))) => Ok(content),
+            (Ok(_), _) => Err(ParseError::UnclosedBracket {
+                bracket: '(',
+                span: open.into(),
+            }),
+        }
+    }
+
+    /// Reads a square-bracket class, after its opening bracket
+    fn class(&mut self, open: TokenMeta) -> Result<Node> {
+        let mut body = vec![];
+        for token in self.tokens.by_ref() {
+            if matches!(token, Token::Raw(']', _)) && closes_class(&body) {
+                self.dialect.check_class(&body)?;
+                return Ok(Node::Class(CharacterClass::try_from(&body[..])?));
+            }
+            match token {
+                // A class can't hold a backreference, so `[\1]` is an octal escape
+                Token::Backreference(number, meta) if group_number(&number).is_some() => {
+                    body.extend(octal_digits(&number, meta));
                 }
-            } else {
-                Err(anyhow!("Ran out of stack"))
+                token => body.push(token),
             }
-        },
-    )?;
+        }
+        Err(ParseError::UnclosedBracket {
+            bracket: '[',
+            span: open.into(),
+        })
+    }
+}
 
-    assert!(!stack.is_empty());
+/// Adds a token that stands for itself, joining it to the literal text before it if there is some
+fn literal(nodes: &mut Vec<Node>, token: Token) {
+    match nodes.last_mut() {
+        Some(Node::Tokens(tokens)) => tokens.push(token),
+        _ => nodes.push(Node::Tokens(vec![token])),
+    }
+}
+
+/// The group a backreference like `\12` refers to by number, which is as good as infinite if it's
+/// too big to count
+fn group_number(number: &str) -> Option<usize> {
+    number
+        .starts_with(|c: char| c.is_ascii_digit())
+        .then(|| number.parse().unwrap_or(usize::MAX))
+}
 
-    stack
+/// How many levels of nodes there are, counting this one
+pub(crate) fn height(node: &Node) -> usize {
+    1 + node
+        .children()
         .into_iter()
-        .try_rfold(Node::Empty, |node, partial| partial.end_with(node))
+        .map(height)
+        .max()
+        .unwrap_or_default()
+}
+
+/// A token that stands alone, like `.`, `^` or `\d`
+fn atom(token: Token, dialect: Dialect) -> Result<Node> {
+    dialect.check_token(&token)?;
+    if let Token::Backreference(name, meta) = token {
+        return Ok(Node::Backreference(Reference::from(name.as_str()), meta));
+    }
+    // Python's `\Z` doesn't match before a final newline, unlike PCRE's
+    let assertion = match (dialect, &token) {
+        (Dialect::Python, Token::Escaped('Z', _)) => Ok(Assertion::EndOfText),
+        _ => Assertion::try_from(&token),
+    };
+    if let Ok(assertion) = assertion {
+        let meta = TokenMeta {
+            position: token.position(),
+        };
+        return Ok(Node::Assertion(assertion, meta));
+    }
+    match ClassItem::for_set(&token)? {
+        Some(item) => Ok(Node::Class(CharacterClass {
+            negated: false,
+            items: vec![item],
+        })),
+        None => Ok(Node::Special((&token).try_into()?)),
+    }
 }
 
 #[cfg(test)]
 #[allow(clippy::expect_used)]
 mod test {
+    use itertools::Itertools;
     use lazy_static::lazy_static;
     use pretty_assertions::assert_eq;
     use rstest::rstest;
 
+    use crate::alphabet::Alphabet;
+    use crate::dialect::Dialect;
+    use crate::parse::Flags;
+    use crate::pattern::Pattern;
     use crate::tokens::Token::*;
     use crate::tokens::{Token, TokenMeta, Tokenise};
+    use crate::tree::class::{CharacterClass, ClassItem};
+    use crate::tree::reference::Reference;
     use crate::tree::special::Special::Star;
     use crate::tree::Node::*;
-    use crate::tree::{tree, Bracket, Node};
+    use crate::tree::{assertion, group, tree, Node};
 
     lazy_static! {
-        static ref STAR: Node = Bracketed(
-            Bracket::Parentheses,
-            Box::new(Augmented(
-                Box::new(Bracketed(
-                    Bracket::Parentheses,
-                    Box::new(Alternate(
-                        Box::new(Empty),
-                        Box::new(Tokens(vec![Raw('a', TokenMeta { position: 3 })]))
-                    ))
-                )),
-                Box::new(Special(Star))
-            ))
-        );
+        static ref STAR: Node = Capture(Box::new(Augmented(
+            Box::new(Capture(Box::new(Alternation(vec![
+                Empty,
+                Tokens(vec![Raw('a', TokenMeta { position: 3 })])
+            ])))),
+            Box::new(Special(Star))
+        )));
     }
 
     #[rstest]
     #[case("a", &Node::Tokens(vec![Token::Raw('a', TokenMeta {position: 0})]))]
     #[case("ab*", &Sequence(vec![Tokens(vec![Raw('a', TokenMeta {position: 0})]), Augmented(Box::new(Tokens(vec![Raw('b', TokenMeta {position: 1})])), Box::new(Special(Star)))]))]
-    #[case("a|", &Alternate(Box::new(Tokens(vec![Raw('a', TokenMeta {position: 0})])), Box::new(Empty)))]
-    #[case("|a", &Alternate(Box::new(Empty), Box::new(Tokens(vec![Raw('a', TokenMeta {position: 1})]))))]
-    #[case("(|a)", &Bracketed(Bracket::Parentheses, Box::new(Alternate(Box::new(Empty), Box::new(Tokens(vec![Raw('a', TokenMeta {position: 2})]))))))]
+    #[case("a|", &Alternation(vec![Tokens(vec![Raw('a', TokenMeta {position: 0})]), Empty]))]
+    #[case("|a", &Alternation(vec![Empty, Tokens(vec![Raw('a', TokenMeta {position: 1})])]))]
+    #[case("(|a)", &Capture(Box::new(Alternation(vec![Empty, Tokens(vec![Raw('a', TokenMeta {position: 2})])]))))]
     #[case("((|a)*)", &STAR)]
-    #[case("(|a\\))", &Bracketed(Bracket::Parentheses, Box::new(Alternate(Box::new(Empty), Box::new(Tokens(vec![Raw('a', TokenMeta {position: 2}),Escaped(')', TokenMeta {position: 3})]))))))]
-    #[case("[*]", &Bracketed(Bracket::Square, Box::new(Special(Star))))]
+    #[case("(|a\\))", &Capture(Box::new(Alternation(vec![Empty, Tokens(vec![Raw('a', TokenMeta {position: 2}),Escaped(')', TokenMeta {position: 3})])]))))]
+    #[case("[*]", &Class(CharacterClass { negated: false, items: vec![ClassItem::Single(Raw('*', TokenMeta {position: 1}))] }))]
+    #[case("a[]|)]", &Sequence(vec![Tokens(vec![Raw('a', TokenMeta {position: 0})]), Class(CharacterClass { negated: false, items: vec![ClassItem::Single(Raw(']', TokenMeta {position: 2})), ClassItem::Single(Raw('|', TokenMeta {position: 3})), ClassItem::Single(Raw(')', TokenMeta {position: 4}))] })]))]
+    #[case("ab{2,}", &Sequence(vec![Tokens(vec![Raw('a', TokenMeta {position: 0})]), Augmented(Box::new(Tokens(vec![Raw('b', TokenMeta {position: 1})])), Box::new(Repetition(crate::tree::repetition::Repetition { min: 2, max: None })))]))]
+    #[case("a{x", &Tokens(vec![Raw('a', TokenMeta {position: 0}), Raw('{', TokenMeta {position: 1}), Raw('x', TokenMeta {position: 2})]))]
+    #[case("a.", &Sequence(vec![Tokens(vec![Raw('a', TokenMeta {position: 0})]), Special(crate::tree::special::Special::Dot)]))]
+    #[case("[a]*", &Augmented(Box::new(Class(CharacterClass { negated: false, items: vec![ClassItem::Single(Raw('a', TokenMeta {position: 1}))] })), Box::new(Special(Star))))]
+    #[case("(?:a)", &Group(group::Group::NonCapturing, Box::new(Tokens(vec![Raw('a', TokenMeta {position: 3})]))))]
+    #[case("x(?P<n>a|)", &Sequence(vec![Tokens(vec![Raw('x', TokenMeta {position: 0})]), Group(group::Group::Named("n".to_string(), TokenMeta {position: 1}), Box::new(Alternation(vec![Tokens(vec![Raw('a', TokenMeta {position: 7})]), Empty])))]))]
+    #[case("a*?", &Lazy(Box::new(Tokens(vec![Raw('a', TokenMeta {position: 0})])), Box::new(Special(Star)), TokenMeta {position: 2}))]
+    #[case("ab{2}+", &Sequence(vec![Tokens(vec![Raw('a', TokenMeta {position: 0})]), Possessive(Box::new(Tokens(vec![Raw('b', TokenMeta {position: 1})])), Box::new(Repetition(crate::tree::repetition::Repetition { min: 2, max: Some(2) })), TokenMeta {position: 5})]))]
+    #[case("(a)\\1", &Sequence(vec![Capture(Box::new(Tokens(vec![Raw('a', TokenMeta {position: 1})]))), Node::Backreference(Reference::Number(1), TokenMeta {position: 3})]))]
+    #[case("(?<n>a)b(?P=n)*", &Sequence(vec![Group(group::Group::Named("n".to_string(), TokenMeta {position: 0}), Box::new(Tokens(vec![Raw('a', TokenMeta {position: 5})]))), Tokens(vec![Raw('b', TokenMeta {position: 7})]), Augmented(Box::new(Node::Backreference(Reference::Name("n".to_string()), TokenMeta {position: 8})), Box::new(Special(Star)))]))]
+    #[case("\\bx\\B$", &Sequence(vec![Assertion(assertion::Assertion::WordBoundary, TokenMeta {position: 0}), Tokens(vec![Raw('x', TokenMeta {position: 2})]), Assertion(assertion::Assertion::NotWordBoundary, TokenMeta {position: 3}), Assertion(assertion::Assertion::Dollar, TokenMeta {position: 5})]))]
+    #[case("a|b|c", &Alternation(vec![Tokens(vec![Raw('a', TokenMeta {position: 0})]), Tokens(vec![Raw('b', TokenMeta {position: 2})]), Tokens(vec![Raw('c', TokenMeta {position: 4})])]))]
+    #[case("a||(b|c)", &Alternation(vec![Tokens(vec![Raw('a', TokenMeta {position: 0})]), Empty, Capture(Box::new(Alternation(vec![Tokens(vec![Raw('b', TokenMeta {position: 4})]), Tokens(vec![Raw('c', TokenMeta {position: 6})])])))]))]
+    #[case("(a?)", &Capture(Box::new(Augmented(Box::new(Tokens(vec![Raw('a', TokenMeta {position: 1})])), Box::new(Special(crate::tree::special::Special::Question))))))]
     fn test_tree(#[case] input: String, #[case] expected: &Node) {
         let tokens = input.tokenise();
         let actual = tree(tokens).expect("Failed to tree");
         assert_eq!(expected, &actual);
     }
+
+    #[rstest]
+    fn test_never_panics() {
+        // Every expression of up to three characters from these, many of which are malformed,
+        // parsed all the way in every dialect, with and without extended mode
+        let chars: Vec<char> = "a(){}[]|*+?^$.\\-,1:<=!Pk'#".chars().collect();
+        let dialects = [
+            Dialect::Pcre,
+            Dialect::PosixBasic,
+            Dialect::PosixExtended,
+            Dialect::JavaScript,
+            Dialect::Python,
+            Dialect::Go,
+            Dialect::Rust,
+        ];
+        let mut inputs = vec![String::new()];
+        for _ in 0..3 {
+            inputs = inputs
+                .iter()
+                .flat_map(|input| chars.iter().map(move |c| format!("{input}{c}")))
+                .collect();
+            for input in &inputs {
+                for (dialect, extended) in dialects.into_iter().cartesian_product([false, true]) {
+                    let flags = Flags {
+                        dialect,
+                        extended,
+                        ..Flags::default()
+                    };
+                    let _ = Pattern::parse_with(input, &Alphabet::default(), flags);
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,55 @@
+use std::fmt::{Display, Formatter};
+
+use crate::error::ParseError;
+use crate::tokens::Token;
+
+/// A zero-width assertion about where in the text we are
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Assertion {
+    /// `^`, the start of the text, or of a line in multi-line mode
+    Caret,
+    /// `$`, the end of the text, or of a line in multi-line mode
+    Dollar,
+    /// `\A`, the start of the text whatever the mode
+    StartOfText,
+    /// `\z`, the end of the text whatever the mode
+    EndOfText,
+    /// `\Z`, the end of the text or just before a newline that ends it
+    EndOfTextOrNewline,
+    /// `\b`, between a word character and something that isn't one
+    WordBoundary,
+    /// `\B`, anywhere that isn't a word boundary
+    NotWordBoundary,
+}
+
+impl TryFrom<&Token> for Assertion {
+    type Error = ParseError;
+
+    fn try_from(value: &Token) -> Result<Self, Self::Error> {
+        match value {
+            Token::Raw('^', ..) => Ok(Assertion::Caret),
+            Token::Raw('$', ..) => Ok(Assertion::Dollar),
+            Token::Escaped('A', ..) => Ok(Assertion::StartOfText),
+            Token::Escaped('z', ..) => Ok(Assertion::EndOfText),
+            Token::Escaped('Z', ..) => Ok(Assertion::EndOfTextOrNewline),
+            Token::Escaped('b', ..) => Ok(Assertion::WordBoundary),
+            Token::Escaped('B', ..) => Ok(Assertion::NotWordBoundary),
+            _ => Err(value.problem()),
+        }
+    }
+}
+
+impl Display for Assertion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Assertion::Caret => "^",
+            Assertion::Dollar => "$",
+            Assertion::StartOfText => "\\A",
+            Assertion::EndOfText => "\\z",
+            Assertion::EndOfTextOrNewline => "\\Z",
+            Assertion::WordBoundary => "\\b",
+            Assertion::NotWordBoundary => "\\B",
+        })
+    }
+}
@@ -0,0 +1,556 @@
+use std::fmt::{Display, Formatter};
+
+use crate::error::{ParseError, Result, Span};
+use crate::tokens::{Token, TokenMeta};
+use crate::unicode;
+
+/// The contents of a square-bracket character class, such as `[^a-z_]`
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) struct CharacterClass {
+    pub(crate) negated: bool,
+    pub(crate) items: Vec<ClassItem>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) enum ClassItem {
+    Single(Token),
+    Range(Token, Token),
+    Shorthand(Shorthand),
+    Property(Property),
+    /// A POSIX named class, such as `[:alpha:]`
+    Named(NamedClass),
+    /// A POSIX equivalence class, such as `[=e=]`, which matches the character and its accented
+    /// forms
+    Equivalence(char),
+}
+
+impl ClassItem {
+    /// The item for a token that stands for a whole set of characters, like `\d` or `\p{L}`
+    pub(crate) fn for_set(token: &Token) -> Result<Option<ClassItem>> {
+        if let Ok(shorthand) = Shorthand::try_from(token) {
+            Ok(Some(ClassItem::Shorthand(shorthand)))
+        } else if let Token::Property(..) = token {
+            Ok(Some(ClassItem::Property(Property::try_from(token)?)))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// The set at the start of `tokens`, if there is one, along with the tokens after it
+    fn set(tokens: &[Token]) -> Result<Option<(ClassItem, &[Token])>> {
+        match PosixBracket::split(tokens) {
+            Some((PosixBracket::Named(name), span, rest)) => match NamedClass::parse(&name) {
+                Some(class) => Ok(Some((ClassItem::Named(class), rest))),
+                None => Err(ParseError::UnknownClass { name, span }),
+            },
+            Some((PosixBracket::Equivalence(name), span, rest)) => {
+                let c = collating_element(&name)
+                    .ok_or(ParseError::UnknownCollatingElement { name, span })?;
+                Ok(Some((ClassItem::Equivalence(c), rest)))
+            }
+            Some((PosixBracket::Collating(_), ..)) => Ok(None),
+            None => match tokens.split_first() {
+                Some((first, rest)) => Ok(ClassItem::for_set(first)?.map(|item| (item, rest))),
+                None => Ok(None),
+            },
+        }
+    }
+}
+
+/// The single character at the start of `tokens`, which is either a token or a collating symbol
+/// like `[.hyphen.]`, along with the tokens after it
+fn element(tokens: &[Token]) -> Result<Option<(Token, &[Token])>> {
+    match PosixBracket::split(tokens) {
+        Some((PosixBracket::Collating(name), span, rest)) => match collating_element(&name) {
+            Some(c) => Ok(Some((
+                Token::Raw(
+                    c,
+                    TokenMeta {
+                        position: span.start,
+                    },
+                ),
+                rest,
+            ))),
+            None => Err(ParseError::UnknownCollatingElement { name, span }),
+        },
+        _ => Ok(tokens
+            .split_first()
+            .map(|(first, rest)| (first.clone(), rest))),
+    }
+}
+
+/// A bracketed POSIX expression within a class
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) enum PosixBracket {
+    /// `[:name:]`
+    Named(String),
+    /// `[=c=]`
+    Equivalence(String),
+    /// `[.c.]`
+    Collating(String),
+}
+
+impl PosixBracket {
+    /// The expression at the start of `tokens`, if there is one, with where it is and the tokens
+    /// after it
+    pub(crate) fn split(tokens: &[Token]) -> Option<(PosixBracket, Span, &[Token])> {
+        let [Token::Raw('[', _), Token::Raw(delimiter @ (':' | '=' | '.'), _), rest @ ..] = tokens
+        else {
+            return None;
+        };
+        let end = rest.windows(2).position(
+            |pair| matches!(pair, [Token::Raw(c, _), Token::Raw(']', _)] if c == delimiter),
+        )?;
+        let name = rest[..end].iter().map(Token::to_char).collect();
+        let span = Span::new(tokens[0].position(), rest[end + 1].position() + 1);
+        let bracket = match delimiter {
+            ':' => PosixBracket::Named(name),
+            '=' => PosixBracket::Equivalence(name),
+            _ => PosixBracket::Collating(name),
+        };
+        Some((bracket, span, &rest[end + 2..]))
+    }
+
+    /// Whether `tokens` end partway through an expression, just before its closing `]`
+    fn unfinished(mut tokens: &[Token]) -> bool {
+        while let Some((_, rest)) = tokens.split_first() {
+            if let Some((_, _, rest)) = PosixBracket::split(tokens) {
+                tokens = rest;
+                continue;
+            }
+            if let [Token::Raw('[', _), Token::Raw(delimiter @ (':' | '=' | '.'), _), .., last] =
+                tokens
+            {
+                if matches!(last, Token::Raw(c, _) if c == delimiter) {
+                    return true;
+                }
+            }
+            tokens = rest;
+        }
+        false
+    }
+}
+
+/// A POSIX named class, with the members it has in the POSIX locale
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum NamedClass {
+    Alnum,
+    Alpha,
+    Blank,
+    Cntrl,
+    Digit,
+    Graph,
+    Lower,
+    Print,
+    Punct,
+    Space,
+    Upper,
+    Word,
+    XDigit,
+}
+
+impl NamedClass {
+    fn parse(name: &str) -> Option<NamedClass> {
+        match name {
+            "alnum" => Some(NamedClass::Alnum),
+            "alpha" => Some(NamedClass::Alpha),
+            "blank" => Some(NamedClass::Blank),
+            "cntrl" => Some(NamedClass::Cntrl),
+            "digit" => Some(NamedClass::Digit),
+            "graph" => Some(NamedClass::Graph),
+            "lower" => Some(NamedClass::Lower),
+            "print" => Some(NamedClass::Print),
+            "punct" => Some(NamedClass::Punct),
+            "space" => Some(NamedClass::Space),
+            "upper" => Some(NamedClass::Upper),
+            "word" => Some(NamedClass::Word),
+            "xdigit" => Some(NamedClass::XDigit),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn ranges(self) -> &'static [(char, char)] {
+        match self {
+            NamedClass::Alnum => &[('0', '9'), ('A', 'Z'), ('a', 'z')],
+            NamedClass::Alpha => &[('A', 'Z'), ('a', 'z')],
+            NamedClass::Blank => &[('\t', '\t'), (' ', ' ')],
+            NamedClass::Cntrl => &[('\0', '\x1F'), ('\x7F', '\x7F')],
+            NamedClass::Digit => &[('0', '9')],
+            NamedClass::Graph => &[('!', '~')],
+            NamedClass::Lower => &[('a', 'z')],
+            NamedClass::Print => &[(' ', '~')],
+            NamedClass::Punct => &[('!', '/'), (':', '@'), ('[', '`'), ('{', '~')],
+            NamedClass::Space => &[('\t', '\r'), (' ', ' ')],
+            NamedClass::Upper => &[('A', 'Z')],
+            NamedClass::Word => &[('0', '9'), ('A', 'Z'), ('_', '_'), ('a', 'z')],
+            NamedClass::XDigit => &[('0', '9'), ('A', 'F'), ('a', 'f')],
+        }
+    }
+}
+
+/// Writes the class the way it appears in a bracket expression, like `[:alpha:]`
+impl Display for NamedClass {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            NamedClass::Alnum => "alnum",
+            NamedClass::Alpha => "alpha",
+            NamedClass::Blank => "blank",
+            NamedClass::Cntrl => "cntrl",
+            NamedClass::Digit => "digit",
+            NamedClass::Graph => "graph",
+            NamedClass::Lower => "lower",
+            NamedClass::Print => "print",
+            NamedClass::Punct => "punct",
+            NamedClass::Space => "space",
+            NamedClass::Upper => "upper",
+            NamedClass::Word => "word",
+            NamedClass::XDigit => "xdigit",
+        };
+        write!(f, "[:{name}:]")
+    }
+}
+
+/// Characters that are the same letter but for their accents, which an equivalence class matches
+/// together
+const EQUIVALENTS: &[&str] = &[
+    "AÀÁÂÃÄÅĀĂĄ",
+    "aàáâãäåāăą",
+    "CÇĆĈĊČ",
+    "cçćĉċč",
+    "DĎ",
+    "dď",
+    "EÈÉÊËĒĔĖĘĚ",
+    "eèéêëēĕėęě",
+    "GĜĞĠĢ",
+    "gĝğġģ",
+    "HĤ",
+    "hĥ",
+    "IÌÍÎÏĨĪĬĮİ",
+    "iìíîïĩīĭį",
+    "JĴ",
+    "jĵ",
+    "KĶ",
+    "kķ",
+    "LĹĻĽ",
+    "lĺļľ",
+    "NÑŃŅŇ",
+    "nñńņň",
+    "OÒÓÔÕÖŌŎŐ",
+    "oòóôõöōŏő",
+    "RŔŖŘ",
+    "rŕŗř",
+    "SŚŜŞŠ",
+    "sśŝşš",
+    "TŢŤ",
+    "tţť",
+    "UÙÚÛÜŨŪŬŮŰŲ",
+    "uùúûüũūŭůűų",
+    "WŴ",
+    "wŵ",
+    "YÝŶŸ",
+    "yýÿŷ",
+    "ZŹŻŽ",
+    "zźżž",
+];
+
+/// The characters equivalent to `c`, including `c` itself
+pub(crate) fn equivalents(c: char) -> Vec<char> {
+    match EQUIVALENTS
+        .iter()
+        .find(|equivalents| equivalents.contains(c))
+    {
+        Some(equivalents) => equivalents.chars().collect(),
+        None => vec![c],
+    }
+}
+
+/// The character that a collating element names, either a single character or one of the names
+/// from the POSIX portable character set
+fn collating_element(name: &str) -> Option<char> {
+    let mut chars = name.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Some(c);
+    }
+    Some(match name {
+        "NUL" => '\0',
+        "alert" => '\x07',
+        "backspace" => '\x08',
+        "tab" => '\t',
+        "newline" => '\n',
+        "vertical-tab" => '\x0B',
+        "form-feed" => '\x0C',
+        "carriage-return" => '\r',
+        "space" => ' ',
+        "exclamation-mark" => '!',
+        "quotation-mark" => '"',
+        "number-sign" => '#',
+        "dollar-sign" => '$',
+        "percent-sign" => '%',
+        "ampersand" => '&',
+        "apostrophe" => '\'',
+        "left-parenthesis" => '(',
+        "right-parenthesis" => ')',
+        "asterisk" => '*',
+        "plus-sign" => '+',
+        "comma" => ',',
+        "hyphen" | "hyphen-minus" => '-',
+        "period" | "full-stop" => '.',
+        "slash" | "solidus" => '/',
+        "zero" => '0',
+        "one" => '1',
+        "two" => '2',
+        "three" => '3',
+        "four" => '4',
+        "five" => '5',
+        "six" => '6',
+        "seven" => '7',
+        "eight" => '8',
+        "nine" => '9',
+        "colon" => ':',
+        "semicolon" => ';',
+        "less-than-sign" => '<',
+        "equals-sign" => '=',
+        "greater-than-sign" => '>',
+        "question-mark" => '?',
+        "commercial-at" => '@',
+        "left-square-bracket" => '[',
+        "backslash" | "reverse-solidus" => '\\',
+        "right-square-bracket" => ']',
+        "circumflex" | "circumflex-accent" => '^',
+        "underscore" | "low-line" => '_',
+        "grave-accent" => '`',
+        "left-brace" | "left-curly-bracket" => '{',
+        "vertical-line" => '|',
+        "right-brace" | "right-curly-bracket" => '}',
+        "tilde" => '~',
+        _ => return None,
+    })
+}
+
+/// A Perl-style shorthand for a class, such as `\d`
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Shorthand {
+    Digit,
+    NotDigit,
+    Word,
+    NotWord,
+    Space,
+    NotSpace,
+}
+
+impl TryFrom<&Token> for Shorthand {
+    type Error = ParseError;
+
+    fn try_from(value: &Token) -> Result<Self> {
+        match value {
+            Token::Escaped('d', _) => Ok(Shorthand::Digit),
+            Token::Escaped('D', _) => Ok(Shorthand::NotDigit),
+            Token::Escaped('w', _) => Ok(Shorthand::Word),
+            Token::Escaped('W', _) => Ok(Shorthand::NotWord),
+            Token::Escaped('s', _) => Ok(Shorthand::Space),
+            Token::Escaped('S', _) => Ok(Shorthand::NotSpace),
+            _ => Err(value.problem()),
+        }
+    }
+}
+
+impl Display for Shorthand {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Shorthand::Digit => "\\d",
+            Shorthand::NotDigit => "\\D",
+            Shorthand::Word => "\\w",
+            Shorthand::NotWord => "\\W",
+            Shorthand::Space => "\\s",
+            Shorthand::NotSpace => "\\S",
+        })
+    }
+}
+
+/// A Unicode property class, such as `\p{Greek}` or `\P{Lu}`
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) struct Property {
+    pub(crate) negated: bool,
+    pub(crate) name: String,
+    pub(crate) ranges: &'static [(char, char)],
+}
+
+impl TryFrom<&Token> for Property {
+    type Error = ParseError;
+
+    fn try_from(value: &Token) -> Result<Self> {
+        match value {
+            Token::Property(negated, name, meta) => {
+                let (negated, name) = match name.strip_prefix('^') {
+                    Some(name) => (!negated, name),
+                    None => (*negated, name.as_str()),
+                };
+                if name.starts_with('{') {
+                    return Err(ParseError::UnclosedProperty {
+                        span: (*meta).into(),
+                    });
+                }
+                let ranges =
+                    unicode::property(name).ok_or_else(|| ParseError::UnknownProperty {
+                        name: name.to_string(),
+                        span: (*meta).into(),
+                    })?;
+                Ok(Property {
+                    negated,
+                    name: name.to_string(),
+                    ranges,
+                })
+            }
+            _ => Err(value.problem()),
+        }
+    }
+}
+
+/// Whether a `]` at this point in the class body would close the class.
+///
+/// A `]` immediately after the opening `[`, or after a leading `^`, is a literal member, and one
+/// that finishes a POSIX bracket expression like `[:alpha:]` belongs to that expression.
+pub(crate) fn closes_class(body: &[Token]) -> bool {
+    !matches!(body, [] | [Token::Raw('^', _)]) && !PosixBracket::unfinished(body)
+}
+
+impl TryFrom<&[Token]> for CharacterClass {
+    type Error = ParseError;
+
+    fn try_from(body: &[Token]) -> Result<Self> {
+        let (negated, mut rest) = match body {
+            [Token::Raw('^', _), rest @ ..] => (true, rest),
+            _ => (false, body),
+        };
+
+        // A backreference can't stand for a single character, so it's as bad as a malformed escape
+        let problematic = |t: &&Token| matches!(t, Token::Malformed(..) | Token::Backreference(..));
+        if let Some(malformed) = body.iter().find(problematic) {
+            return Err(malformed.problem());
+        }
+
+        let mut items = vec![];
+        while !rest.is_empty() {
+            if let Some((item, remainder)) = ClassItem::set(rest)? {
+                items.push(item);
+                rest = remainder;
+                continue;
+            }
+            let Some((first, remainder)) = element(rest)? else {
+                break;
+            };
+            match remainder {
+                // A set can't be the end of a range, so the `-` is literal
+                [Token::Raw('-', _), tail @ ..] if ClassItem::set(tail)?.is_none() => {
+                    let Some((last, remainder)) = element(tail)? else {
+                        items.push(ClassItem::Single(first));
+                        rest = remainder;
+                        continue;
+                    };
+                    if first.to_char() > last.to_char() {
+                        return Err(ParseError::InvalidRange {
+                            range: format!("{first}-{last}"),
+                            span: Span::new(first.position(), last.position() + 1),
+                        });
+                    }
+                    items.push(ClassItem::Range(first, last));
+                    rest = remainder;
+                }
+                _ => {
+                    items.push(ClassItem::Single(first));
+                    rest = remainder;
+                }
+            }
+        }
+
+        Ok(CharacterClass { negated, items })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod test {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::tokens::Token::Raw;
+    use crate::tokens::{TokenMeta, Tokenise};
+    use crate::tree::class::{closes_class, CharacterClass, ClassItem, NamedClass};
+
+    fn chars(class: &CharacterClass) -> Vec<(char, char)> {
+        class
+            .items
+            .iter()
+            .map(|item| match item {
+                ClassItem::Single(t) => (t.to_char(), t.to_char()),
+                ClassItem::Range(a, b) => (a.to_char(), b.to_char()),
+                ClassItem::Shorthand(_)
+                | ClassItem::Property(_)
+                | ClassItem::Named(_)
+                | ClassItem::Equivalence(_) => ('\\', '\\'),
+            })
+            .collect()
+    }
+
+    #[rstest]
+    #[case("a", false, vec![('a', 'a')])]
+    #[case("a-z", false, vec![('a', 'z')])]
+    #[case("^a-z_", true, vec![('a', 'z'), ('_', '_')])]
+    #[case("-a", false, vec![('-', '-'), ('a', 'a')])]
+    #[case("a-", false, vec![('a', 'a'), ('-', '-')])]
+    #[case("]a", false, vec![(']', ']'), ('a', 'a')])]
+    #[case("^]", true, vec![(']', ']')])]
+    #[case("a^", false, vec![('a', 'a'), ('^', '^')])]
+    #[case("\\]\\-\\\\", false, vec![(']', ']'), ('-', '-'), ('\\', '\\')])]
+    #[case("a\\-z", false, vec![('a', 'a'), ('-', '-'), ('z', 'z')])]
+    #[case("!--", false, vec![('!', '-')])]
+    #[case("\\d-z", false, vec![('\\', '\\'), ('-', '-'), ('z', 'z')])]
+    #[case("\\p{L}-z", false, vec![('\\', '\\'), ('-', '-'), ('z', 'z')])]
+    #[case("a-\\d", false, vec![('a', 'a'), ('-', '-'), ('\\', '\\')])]
+    fn test_class(
+        #[case] input: String,
+        #[case] negated: bool,
+        #[case] expected: Vec<(char, char)>,
+    ) {
+        let tokens: Vec<_> = input.tokenise().collect();
+        let class = CharacterClass::try_from(&tokens[..]).expect("Failed to parse class");
+        assert_eq!(negated, class.negated);
+        assert_eq!(expected, chars(&class));
+    }
+
+    #[rstest]
+    #[case("[:alpha:]", vec![ClassItem::Named(NamedClass::Alpha)])]
+    #[case("^[:digit:]_", vec![ClassItem::Named(NamedClass::Digit), ClassItem::Single(Raw('_', TokenMeta { position: 10 }))])]
+    #[case("[=e=]", vec![ClassItem::Equivalence('e')])]
+    #[case("[.hyphen.]-z", vec![ClassItem::Range(Raw('-', TokenMeta { position: 0 }), Raw('z', TokenMeta { position: 11 }))])]
+    #[case("a-[.c.]", vec![ClassItem::Range(Raw('a', TokenMeta { position: 0 }), Raw('c', TokenMeta { position: 2 }))])]
+    #[case("[:upper:]-", vec![ClassItem::Named(NamedClass::Upper), ClassItem::Single(Raw('-', TokenMeta { position: 9 }))])]
+    #[case("[:a", vec![ClassItem::Single(Raw('[', TokenMeta { position: 0 })), ClassItem::Single(Raw(':', TokenMeta { position: 1 })), ClassItem::Single(Raw('a', TokenMeta { position: 2 }))])]
+    fn test_posix(#[case] input: String, #[case] expected: Vec<ClassItem>) {
+        let tokens: Vec<_> = input.tokenise().collect();
+        let class = CharacterClass::try_from(&tokens[..]).expect("Failed to parse class");
+        assert_eq!(expected, class.items);
+    }
+
+    #[rstest]
+    #[case("[:alpha", true)]
+    #[case("[:alpha:", false)]
+    #[case("[:alpha:][=e", true)]
+    #[case("[=e=", false)]
+    #[case("[:", true)]
+    #[case("^", false)]
+    fn test_closes_class(#[case] input: String, #[case] expected: bool) {
+        let tokens: Vec<_> = input.tokenise().collect();
+        assert_eq!(expected, closes_class(&tokens));
+    }
+
+    #[rstest]
+    fn test_invalid_range() {
+        let tokens: Vec<_> = "z-a".to_string().tokenise().collect();
+        let class = CharacterClass::try_from(&tokens[..]);
+        let error = class.expect_err("Should not parse");
+        assert_eq!(format!("{error}"), "Invalid range z-a at position 0");
+    }
+}
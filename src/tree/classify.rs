@@ -6,6 +6,7 @@ pub(crate) enum TokenClass {
     CloseBracket,
     Augmentation,
     Alternation,
+    Atom,
     Regular,
     Problematic,
 }
@@ -17,15 +18,24 @@ pub(crate) trait Classify {
 impl Classify for Token {
     fn classify(&self) -> TokenClass {
         match self {
-            Token::Escaped(..) => TokenClass::Regular,
+            Token::Escaped(c, ..) => match c {
+                'd' | 'D' | 'w' | 'W' | 's' | 'S' => TokenClass::Atom,
+                'b' | 'B' | 'A' | 'z' | 'Z' => TokenClass::Atom,
+                _ => TokenClass::Regular,
+            },
             Token::Raw(c, ..) => match c {
                 '(' | '[' | '{' => TokenClass::OpenBracket,
                 ')' | ']' | '}' => TokenClass::CloseBracket,
-                '*' | '?' => TokenClass::Augmentation,
+                '*' | '+' | '?' => TokenClass::Augmentation,
                 '|' => TokenClass::Alternation,
+                '.' | '^' | '$' => TokenClass::Atom,
                 _ => TokenClass::Regular,
             },
             Token::TrailingEscapeCharacter(..) => TokenClass::Problematic,
+            Token::Property(..) => TokenClass::Atom,
+            Token::Encoded(..) => TokenClass::Regular,
+            Token::Malformed(..) => TokenClass::Problematic,
+            Token::Backreference(..) => TokenClass::Atom,
         }
     }
 }
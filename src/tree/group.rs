@@ -0,0 +1,215 @@
+use crate::error::{ParseError, Result, Span};
+use crate::tokens::{Token, TokenMeta};
+
+/// The kind of a parenthesised group that starts with `(?`
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) enum Group {
+    /// `(?:...)`, which groups without capturing
+    NonCapturing,
+    /// `(?P<name>...)`, `(?<name>...)` or `(?'name'...)`, which captures under a name, starting
+    /// at the opening parenthesis
+    Named(String, TokenMeta),
+    /// `(?>...)`, which never backtracks into the group once it has matched, starting at the
+    /// opening parenthesis
+    Atomic(TokenMeta),
+    /// `(?=...)` or `(?!...)`, which checks what follows without consuming it
+    Lookahead { negated: bool },
+    /// `(?<=...)` or `(?<!...)`, which checks what came before
+    Lookbehind { negated: bool },
+    /// `(?i:...)`, which changes flags inside the group
+    Scoped(Modifiers),
+    /// `(?i)`, which changes flags for the rest of the enclosing group
+    Flags(Modifiers),
+    /// `(?P=name)`, which isn't really a group but a backreference to the named group
+    Reference(String),
+}
+
+/// The flags switched on and off by a group like `(?im-s)`
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Modifiers {
+    pub on: Vec<Flag>,
+    pub off: Vec<Flag>,
+}
+
+/// A flag that a group can switch on or off
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Flag {
+    /// `i`
+    CaseInsensitive,
+    /// `m`
+    MultiLine,
+    /// `s`
+    DotAll,
+    /// `u`
+    Unicode,
+    /// `x`
+    Extended,
+}
+
+impl Flag {
+    fn from_letter(letter: char) -> Option<Flag> {
+        match letter {
+            'i' => Some(Flag::CaseInsensitive),
+            'm' => Some(Flag::MultiLine),
+            's' => Some(Flag::DotAll),
+            'u' => Some(Flag::Unicode),
+            'x' => Some(Flag::Extended),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn letter(self) -> char {
+        match self {
+            Flag::CaseInsensitive => 'i',
+            Flag::MultiLine => 'm',
+            Flag::DotAll => 's',
+            Flag::Unicode => 'u',
+            Flag::Extended => 'x',
+        }
+    }
+}
+
+impl Group {
+    /// Interprets the tokens that follow `(?`.
+    ///
+    /// Returns `None` if the header isn't finished yet, so more tokens are needed.
+    pub(crate) fn parse(question: &Token, header: &[Token]) -> Result<Option<Group>> {
+        let position = question.position().saturating_sub(1);
+        let end = header.last().unwrap_or(question).position() + 1;
+        let span = Span::new(position, end);
+        let text: String = header.iter().map(|t| t.to_char()).collect();
+        let unknown = || ParseError::UnknownGroup {
+            syntax: text.clone(),
+            span,
+        };
+        if header.iter().any(|t| !matches!(t, Token::Raw(..))) {
+            return Err(unknown());
+        }
+        if text.starts_with(|c: char| c == '-' || c.is_ascii_lowercase()) {
+            return modifiers(&text, span);
+        }
+
+        let named = |name| Group::Named(name, TokenMeta { position });
+        let (name, closing, group): (_, _, &dyn Fn(String) -> Group) = match text.as_str() {
+            "" | "P" => return Ok(None),
+            ":" => return Ok(Some(Group::NonCapturing)),
+            ">" => return Ok(Some(Group::Atomic(TokenMeta { position }))),
+            "=" => return Ok(Some(Group::Lookahead { negated: false })),
+            "!" => return Ok(Some(Group::Lookahead { negated: true })),
+            "<=" => return Ok(Some(Group::Lookbehind { negated: false })),
+            "<!" => return Ok(Some(Group::Lookbehind { negated: true })),
+            _ => match text.strip_prefix("P<").or(text.strip_prefix('<')) {
+                Some(name) => (name, '>', &named),
+                None => match text.strip_prefix("P=") {
+                    Some(name) => (name, ')', &Group::Reference),
+                    None => (text.strip_prefix('\'').ok_or_else(unknown)?, '\'', &named),
+                },
+            },
+        };
+        match name.strip_suffix(closing) {
+            Some("") => Err(ParseError::EmptyGroupName { span }),
+            Some(name) => Ok(Some(group(name.to_string()))),
+            None if is_name(name) => Ok(None),
+            None => Err(ParseError::InvalidGroupName {
+                name: name.to_string(),
+                span,
+            }),
+        }
+    }
+}
+
+/// Interprets a flag group like `(?i)` or `(?m-s:`, which may not be finished yet
+fn modifiers(text: &str, span: Span) -> Result<Option<Group>> {
+    let (body, end) = match text.char_indices().last() {
+        Some((i, c @ (':' | ')'))) => (&text[..i], Some(c)),
+        _ => (text, None),
+    };
+    let (on, off) = body.split_once('-').unwrap_or((body, ""));
+    // The letters start after the `(?`, and the ones switched off after the `-` too
+    let flags = |letters: &str, offset: usize| {
+        letters
+            .chars()
+            .enumerate()
+            .map(|(i, c)| {
+                let position = span.start + offset + i;
+                Flag::from_letter(c).ok_or(ParseError::UnknownFlag {
+                    flag: c,
+                    span: Span::new(position, position + 1),
+                })
+            })
+            .collect::<Result<Vec<_>>>()
+    };
+    let modifiers = Modifiers {
+        on: flags(on, 2)?,
+        off: flags(off, on.chars().count() + 3)?,
+    };
+    match end {
+        None => Ok(None),
+        Some(_) if modifiers.on.is_empty() && modifiers.off.is_empty() => {
+            Err(ParseError::NoFlags { span })
+        }
+        Some(':') => Ok(Some(Group::Scoped(modifiers))),
+        Some(_) => Ok(Some(Group::Flags(modifiers))),
+    }
+}
+
+/// Whether the text so far is (the start of) a valid group name
+fn is_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().map_or(true, |c| c == '_' || c.is_alphabetic())
+        && chars.all(|c| c == '_' || c.is_alphanumeric())
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod test {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::tokens::{Token, TokenMeta, Tokenise};
+    use crate::tree::group::{Flag, Group, Modifiers};
+
+    const QUESTION: Token = Token::Raw('?', TokenMeta { position: 1 });
+
+    #[rstest]
+    #[case("", None)]
+    #[case(":", Some(Group::NonCapturing))]
+    #[case(">", Some(Group::Atomic(TokenMeta { position: 0 })))]
+    #[case("P", None)]
+    #[case("P<na", None)]
+    #[case("<", None)]
+    #[case("!", Some(Group::Lookahead { negated: true }))]
+    #[case("<=", Some(Group::Lookbehind { negated: false }))]
+    #[case("P<name>", Some(Group::Named("name".to_string(), TokenMeta { position: 0 })))]
+    #[case("<_x1>", Some(Group::Named("_x1".to_string(), TokenMeta { position: 0 })))]
+    #[case("'name'", Some(Group::Named("name".to_string(), TokenMeta { position: 0 })))]
+    #[case("P=na", None)]
+    #[case("P=name)", Some(Group::Reference("name".to_string())))]
+    #[case("i", None)]
+    #[case("i)", Some(Group::Flags(Modifiers { on: vec![Flag::CaseInsensitive], off: vec![] })))]
+    #[case("sm-i:", Some(Group::Scoped(Modifiers { on: vec![Flag::DotAll, Flag::MultiLine], off: vec![Flag::CaseInsensitive] })))]
+    #[case("x-i:", Some(Group::Scoped(Modifiers { on: vec![Flag::Extended], off: vec![Flag::CaseInsensitive] })))]
+    #[case("-u)", Some(Group::Flags(Modifiers { on: vec![], off: vec![Flag::Unicode] })))]
+    fn test_group(#[case] input: String, #[case] expected: Option<Group>) {
+        let tokens: Vec<_> = input.tokenise().collect();
+        let actual = Group::parse(&QUESTION, &tokens).expect("Failed to parse group");
+        assert_eq!(expected, actual);
+    }
+
+    #[rstest]
+    #[case("Q", "Unknown group syntax (?Q at position 0")]
+    #[case("\\:", "Unknown group syntax (?: at position 0")]
+    #[case("<>", "Group at position 0 has an empty name")]
+    #[case("P=)", "Group at position 0 has an empty name")]
+    #[case("<1", "Invalid group name \"1\" at position 0")]
+    #[case("P<a-", "Invalid group name \"a-\" at position 0")]
+    #[case("iq", "Unknown flag 'q' at position 3")]
+    #[case("i-m-s", "Unknown flag '-' at position 5")]
+    #[case("-:", "Group at position 0 has no flags")]
+    fn test_invalid_group(#[case] input: String, #[case] expected: &str) {
+        let tokens: Vec<_> = input.tokenise().collect();
+        let error = Group::parse(&QUESTION, &tokens).expect_err("Should not parse");
+        assert_eq!(format!("{error}"), expected);
+    }
+}
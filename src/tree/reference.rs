@@ -0,0 +1,29 @@
+use std::fmt::{Display, Formatter};
+
+/// The capture group that a backreference repeats
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Reference {
+    /// `\1`, counting opening parentheses from the left
+    Number(usize),
+    /// `\k<name>` or `(?P=name)`
+    Name(String),
+}
+
+impl From<&str> for Reference {
+    fn from(text: &str) -> Self {
+        match text.parse() {
+            Ok(number) => Reference::Number(number),
+            Err(_) => Reference::Name(text.to_string()),
+        }
+    }
+}
+
+impl Display for Reference {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Reference::Number(number) => write!(f, "{number}"),
+            Reference::Name(name) => write!(f, "{name:?}"),
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use crate::error::{ParseError, Result, Span};
+use crate::tokens::Token;
+
+/// A counted repetition, such as `{2}`, `{2,}` or `{2,5}`
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) struct Repetition {
+    pub(crate) min: u32,
+    pub(crate) max: Option<u32>,
+}
+
+impl Repetition {
+    /// Interprets the tokens between a pair of braces, reporting errors against the `span` of
+    /// the braces themselves.
+    ///
+    /// Returns `None` if the braces don't hold a quantifier, in which case they should be treated
+    /// as literal text.
+    pub(crate) fn parse(body: &[Token], span: Span) -> Result<Option<Repetition>> {
+        let text: String = body.iter().map(|t| t.to_char()).collect();
+        let number = |digits: &str| {
+            digits
+                .parse::<u32>()
+                .map_err(|e| ParseError::InvalidRepetitionCount {
+                    count: digits.to_string(),
+                    reason: e.to_string(),
+                    span,
+                })
+        };
+
+        let (min, max) = match text.split_once(',') {
+            None => (text.as_str(), Some(text.as_str())),
+            Some((min, "")) => (min, None),
+            Some((min, max)) => (min, Some(max)),
+        };
+        let is_count = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_digit());
+        if !is_count(min) || !max.map_or(true, is_count) {
+            return Ok(None);
+        }
+
+        let min = number(min)?;
+        let max = max.map(number).transpose()?;
+        if let Some(max) = max {
+            if max < min {
+                return Err(ParseError::RepetitionOutOfOrder { text, span });
+            }
+        }
+        Ok(Some(Repetition { min, max }))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod test {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::error::Span;
+    use crate::tokens::Tokenise;
+    use crate::tree::repetition::Repetition;
+
+    #[rstest]
+    #[case("3", Some(Repetition { min: 3, max: Some(3) }))]
+    #[case("3,", Some(Repetition { min: 3, max: None }))]
+    #[case("3,5", Some(Repetition { min: 3, max: Some(5) }))]
+    #[case("", None)]
+    #[case(",5", None)]
+    #[case("1,2,3", None)]
+    fn test_repetition(#[case] input: String, #[case] expected: Option<Repetition>) {
+        let tokens: Vec<_> = input.tokenise().collect();
+        let actual = Repetition::parse(&tokens, Span::new(0, input.len()))
+            .expect("Failed to parse repetition");
+        assert_eq!(expected, actual);
+    }
+
+    #[rstest]
+    #[case("5,3", "Repetition {5,3} at position 0 has its bounds out of order")]
+    #[case(
+        "99999999999",
+        "Invalid repetition count 99999999999 at position 0: number too large to fit in target type"
+    )]
+    fn test_invalid(#[case] input: String, #[case] expected: &str) {
+        let tokens: Vec<_> = input.tokenise().collect();
+        let error =
+            Repetition::parse(&tokens, Span::new(0, input.len())).expect_err("Should not parse");
+        assert_eq!(expected, format!("{error}"));
+    }
+}
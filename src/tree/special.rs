@@ -1,27 +1,24 @@
-use anyhow::anyhow;
-
+use crate::error::ParseError;
 use crate::tokens::Token;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub(crate) enum Special {
     Star,
+    Plus,
     Question,
-    Caret,
-    Dollar,
     Dot,
 }
 
-impl TryFrom<Token> for Special {
-    type Error = anyhow::Error;
+impl TryFrom<&Token> for Special {
+    type Error = ParseError;
 
-    fn try_from(value: Token) -> Result<Self, Self::Error> {
+    fn try_from(value: &Token) -> Result<Self, Self::Error> {
         match value {
             Token::Raw('*', ..) => Ok(Special::Star),
+            Token::Raw('+', ..) => Ok(Special::Plus),
             Token::Raw('?', ..) => Ok(Special::Question),
-            Token::Raw('^', ..) => Ok(Special::Caret),
-            Token::Raw('$', ..) => Ok(Special::Dollar),
             Token::Raw('.', ..) => Ok(Special::Dot),
-            _ => Err(anyhow!("{value:?} is not a special character")),
+            _ => Err(value.problem()),
         }
     }
 }
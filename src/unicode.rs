@@ -0,0 +1,131 @@
+#[rustfmt::skip]
+mod general_category;
+#[rustfmt::skip]
+mod perl_word;
+#[rustfmt::skip]
+mod property_bool;
+#[rustfmt::skip]
+mod property_names;
+#[rustfmt::skip]
+mod property_values;
+#[rustfmt::skip]
+mod script;
+
+pub(crate) use general_category::DECIMAL_NUMBER;
+pub(crate) use perl_word::PERL_WORD;
+pub(crate) use property_bool::WHITE_SPACE;
+
+const ANY: &[(char, char)] = &[('\0', char::MAX)];
+const ASCII: &[(char, char)] = &[('\0', '\x7F')];
+
+/// Finds the characters with a property, as named in `\p{..}`.
+///
+/// The name may be a general category, a script, or a binary property, or it may name the kind
+/// of property explicitly, as in `Script=Greek`.  Names are matched loosely, ignoring case,
+/// spaces, hyphens and underscores.
+pub(crate) fn property(name: &str) -> Option<&'static [(char, char)]> {
+    match name.split_once(['=', ':']) {
+        Some((property, value)) => match canonical(property_names::PROPERTY_NAMES, property)? {
+            "General_Category" => general_category(value),
+            "Script" => script(value),
+            _ => None,
+        },
+        None => match loose(name).as_str() {
+            "any" => Some(ANY),
+            "ascii" => Some(ASCII),
+            _ => general_category(name)
+                .or_else(|| script(name))
+                .or_else(|| binary_property(name)),
+        },
+    }
+}
+
+fn general_category(value: &str) -> Option<&'static [(char, char)]> {
+    let name = canonical(values("General_Category")?, value)?;
+    lookup(general_category::BY_NAME, name)
+}
+
+fn script(value: &str) -> Option<&'static [(char, char)]> {
+    let name = canonical(values("Script")?, value)?;
+    lookup(script::BY_NAME, name)
+}
+
+fn binary_property(name: &str) -> Option<&'static [(char, char)]> {
+    let name = canonical(property_names::PROPERTY_NAMES, name)?;
+    lookup(property_bool::BY_NAME, name)
+}
+
+/// The aliases for the values of a property
+fn values(property: &str) -> Option<&'static [(&'static str, &'static str)]> {
+    property_values::PROPERTY_VALUES
+        .iter()
+        .find(|(name, _)| *name == property)
+        .map(|(_, values)| *values)
+}
+
+fn canonical(aliases: &'static [(&'static str, &'static str)], name: &str) -> Option<&'static str> {
+    let name = loose(name);
+    aliases
+        .binary_search_by_key(&name.as_str(), |(alias, _)| alias)
+        .ok()
+        .map(|i| aliases[i].1)
+}
+
+fn lookup(
+    table: &'static [(&'static str, &'static [(char, char)])],
+    name: &str,
+) -> Option<&'static [(char, char)]> {
+    table
+        .iter()
+        .find(|(n, _)| *n == name)
+        .map(|(_, ranges)| *ranges)
+}
+
+/// Normalises a name as in UAX44-LM3, which says to ignore case, spaces, hyphens and underscores
+fn loose(name: &str) -> String {
+    name.chars()
+        .filter(|c| !matches!(c, ' ' | '-' | '_'))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::unicode::property;
+
+    #[rstest]
+    #[case("L", 'é', true)]
+    #[case("Letter", '1', false)]
+    #[case("Nd", '٣', true)]
+    #[case("lu", 'A', true)]
+    #[case("Uppercase Letter", 'a', false)]
+    #[case("Greek", 'λ', true)]
+    #[case("greek", 'l', false)]
+    #[case("sc=Grek", 'λ', true)]
+    #[case("Script=Cyrillic", 'ж', true)]
+    #[case("gc:Lu", 'Ж', true)]
+    #[case("Alphabetic", 'ж', true)]
+    #[case("White_Space", '\u{2003}', true)]
+    #[case("Emoji", '😀', true)]
+    #[case("Any", '\u{10FFFF}', true)]
+    #[case("ASCII", 'é', false)]
+    fn test_property(#[case] name: &str, #[case] c: char, #[case] expected: bool) {
+        let ranges = property(name).unwrap_or_default();
+        assert!(!ranges.is_empty());
+        let actual = ranges
+            .iter()
+            .any(|(start, end)| (*start..=*end).contains(&c));
+        assert_eq!(expected, actual);
+    }
+
+    #[rstest]
+    #[case("Klingon")]
+    #[case("sc=Lu")]
+    #[case("Greek=Script")]
+    fn test_unknown(#[case] name: &str) {
+        assert_eq!(None, property(name));
+    }
+}